@@ -0,0 +1,192 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufRead, BufReader},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::{Args, Parser, ValueEnum};
+
+use crate::{solve_with, DayResult, InputError, ParseError, Solution};
+
+/// Where to read the puzzle input from. Shared by the `aoc` runner and every
+/// day's own binary.
+#[derive(Args, Debug, Clone, Default)]
+pub struct InputArgs {
+    /// Read the puzzle input from this file instead of `inputs/input.txt` (`-` reads stdin)
+    #[arg(short, long, value_name = "PATH", conflicts_with = "example")]
+    pub input: Option<PathBuf>,
+
+    /// Read the example input from `inputs/example.txt`
+    #[arg(long)]
+    pub example: bool,
+}
+
+impl InputArgs {
+    /// Path of the input file for the day living in `day_dir`.
+    pub fn path(&self, day_dir: &Path) -> PathBuf {
+        match &self.input {
+            Some(path) => path.clone(),
+            None if self.example => day_dir.join("inputs/example.txt"),
+            None => day_dir.join("inputs/input.txt"),
+        }
+    }
+
+    pub fn reads_stdin(&self) -> bool {
+        self.input.as_deref() == Some(Path::new("-"))
+    }
+
+    pub fn read(&self, day_dir: &Path) -> io::Result<String> {
+        if self.reads_stdin() {
+            io::read_to_string(io::stdin())
+        } else {
+            fs::read_to_string(self.path(day_dir))
+        }
+    }
+
+    /// Opens the input for streaming instead of reading it at once.
+    pub fn open(&self, day_dir: &Path) -> io::Result<Box<dyn BufRead>> {
+        if self.reads_stdin() {
+            Ok(Box::new(io::stdin().lock()))
+        } else {
+            Ok(Box::new(BufReader::new(File::open(self.path(day_dir))?)))
+        }
+    }
+
+    /// Renders a parse error of the input with its offending line. A
+    /// streamed input is gone by then, so the file is read again (stdin
+    /// can't be read twice and is rendered without the line).
+    pub fn render_error(&self, day_dir: &Path, err: &ParseError) -> String {
+        let source = if self.reads_stdin() {
+            String::new()
+        } else {
+            self.read(day_dir).unwrap_or_default()
+        };
+        err.render(&self.describe(day_dir), &source)
+    }
+
+    /// Human readable name of the input, used in error messages.
+    pub fn describe(&self, day_dir: &Path) -> String {
+        if self.reads_stdin() {
+            String::from("<stdin>")
+        } else {
+            self.path(day_dir).display().to_string()
+        }
+    }
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Answers and timings for people, followed by any visualization
+    #[default]
+    Text,
+    /// One `{day, part, answer, elapsed_ms}` object per line, without visualizations
+    Json,
+}
+
+/// How results are printed, shared like [`InputArgs`].
+#[derive(Args, Debug, Clone, Default)]
+pub struct OutputArgs {
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+}
+
+impl OutputArgs {
+    /// Whether the visualization is shown and so worth rendering.
+    pub fn visualize(&self) -> bool {
+        self.format == Format::Text
+    }
+
+    pub fn render(&self, result: &DayResult) -> String {
+        match self.format {
+            Format::Text => result.to_string(),
+            Format::Json => result.json_lines(),
+        }
+    }
+}
+
+/// Options every day's binary understands. Days with options of their own
+/// flatten this into their own parser and call [`run`] or [`load`].
+#[derive(Args, Debug, Clone, Default)]
+pub struct DayArgs {
+    #[command(flatten)]
+    pub input: InputArgs,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Parser)]
+#[command(about = "Solves a single day of Advent of Code 2024")]
+struct DayCli {
+    #[command(flatten)]
+    day: DayArgs,
+}
+
+/// Day binaries run from the day's directory.
+const DAY_DIR: &str = "";
+
+/// Opens the input of a day's own binary, see [`InputArgs::open`].
+pub fn open(input: &InputArgs) -> io::Result<Box<dyn BufRead>> {
+    input.open(Path::new(DAY_DIR))
+}
+
+/// Names the input of a day's own binary in error messages.
+pub fn describe(input: &InputArgs) -> String {
+    input.describe(Path::new(DAY_DIR))
+}
+
+fn report(input: &InputArgs, err: InputError) {
+    match err {
+        InputError::Io(err) => eprintln!("Unable to read {}: {err}", describe(input)),
+        InputError::Parse(err) => eprintln!("{}", input.render_error(Path::new(DAY_DIR), &err)),
+        err @ InputError::Unsolved { .. } => eprintln!("{}: {err}", describe(input)),
+    }
+}
+
+/// Streams the input into [`Solution::parse_reader`], reporting failures on
+/// stderr.
+pub fn load<S: Solution>(input: &InputArgs) -> Option<S::Input> {
+    load_with(input, S::parse_reader)
+}
+
+/// Like [`load`], but with a parser of the day's own choosing.
+pub fn load_with<T>(
+    input: &InputArgs,
+    parse: impl FnOnce(&mut dyn BufRead) -> Result<T, InputError>,
+) -> Option<T> {
+    let parsed = open(input)
+        .map_err(InputError::from)
+        .and_then(|mut reader| parse(&mut reader));
+    parsed.map_err(|err| report(input, err)).ok()
+}
+
+/// Solves `S` and prints the result in the requested format.
+pub fn run<S: Solution>(args: &DayArgs) -> ExitCode {
+    run_with::<S>(args, S::parse_reader)
+}
+
+/// Like [`run`], but with a parser of the day's own choosing.
+pub fn run_with<S: Solution>(
+    args: &DayArgs,
+    parse: impl FnOnce(&mut dyn BufRead) -> Result<S::Input, InputError>,
+) -> ExitCode {
+    let result = open(&args.input)
+        .map_err(InputError::from)
+        .and_then(|mut reader| solve_with::<S>(&mut reader, args.output.visualize(), parse));
+    match result {
+        Ok(result) => {
+            println!("{}", args.output.render(&result));
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            report(&args.input, err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Entry point of a day's binary without options of its own.
+pub fn main<S: Solution>() -> ExitCode {
+    run::<S>(&DayCli::parse().day)
+}
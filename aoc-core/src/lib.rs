@@ -0,0 +1,315 @@
+pub mod cli;
+pub mod parse;
+pub mod table;
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    hint::black_box,
+    io::{self, BufRead},
+    time::{Duration, Instant},
+};
+
+pub use parse::ParseError;
+use serde::Serialize;
+
+/// A single day of the calendar. The runner parses the puzzle input once and
+/// hands the result to both parts.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Parses the input straight from a reader. Days with large inputs can
+    /// override this to avoid holding the whole text in memory, by default
+    /// the input is read completely and handed to [`Solution::parse`].
+    fn parse_reader(reader: &mut dyn BufRead) -> Result<Self::Input, InputError> {
+        let contents = io::read_to_string(reader)?;
+        Ok(Self::parse(&contents)?)
+    }
+    fn part1(input: &Self::Input) -> Self::Part1;
+
+    /// Part 2 can find that an input which parsed fine has no answer (e.g.
+    /// an update no order of its pages satisfies).
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError>;
+
+    /// Optional picture of the puzzle state (e.g. the final warehouse map),
+    /// printed after the answers.
+    fn visualize(_input: &Self::Input) -> Option<String> {
+        None
+    }
+}
+
+/// An input without an answer although it parsed fine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError(pub String);
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for SolveError {}
+
+/// Failure to read, parse or solve an input that is streamed from a reader.
+#[derive(Debug)]
+pub enum InputError {
+    Io(io::Error),
+    Parse(ParseError),
+    /// The input parsed, but part `part` has no answer for it.
+    Unsolved {
+        part: u8,
+        err: SolveError,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Io(err) => write!(f, "{err}"),
+            InputError::Parse(err) => write!(f, "{err}"),
+            InputError::Unsolved { part, err } => write!(f, "part {part} has no answer: {err}"),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+impl From<io::Error> for InputError {
+    fn from(err: io::Error) -> InputError {
+        InputError::Io(err)
+    }
+}
+
+impl From<ParseError> for InputError {
+    fn from(err: ParseError) -> InputError {
+        InputError::Parse(err)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct DayResult {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub parts: [PartResult; 2],
+    pub visualization: Option<String>,
+}
+
+impl Display for DayResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {}", self.day)?;
+        for part in &self.parts {
+            write!(
+                f,
+                "\n  Part {}: {} ({:.2?})",
+                part.part, part.answer, part.elapsed
+            )?;
+        }
+        if let Some(visualization) = &self.visualization {
+            write!(f, "\n{visualization}")?;
+        }
+        Ok(())
+    }
+}
+
+/// One line of the JSON output.
+#[derive(Serialize)]
+struct PartRecord<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    elapsed_ms: f64,
+}
+
+impl DayResult {
+    /// One JSON object per part, each on its own line. The visualization is
+    /// left out.
+    pub fn json_lines(&self) -> String {
+        self.parts
+            .iter()
+            .map(|part| {
+                serde_json::to_string(&PartRecord {
+                    day: self.day,
+                    part: part.part,
+                    answer: &part.answer,
+                    elapsed_ms: part.elapsed.as_nanos() as f64 / 1e6,
+                })
+                .unwrap()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
+
+/// Parses `contents` and runs both parts of `S`, timing every stage. The
+/// visualization is only rendered if `visualize` is set.
+pub fn solve<S: Solution>(contents: &str, visualize: bool) -> Result<DayResult, InputError> {
+    let (input, parse_time) = timed(|| S::parse(contents));
+    run::<S>(&input?, parse_time, visualize)
+}
+
+/// Like [`solve`], but parses the input with [`Solution::parse_reader`].
+pub fn solve_reader<S: Solution>(
+    reader: &mut dyn BufRead,
+    visualize: bool,
+) -> Result<DayResult, InputError> {
+    solve_with::<S>(reader, visualize, S::parse_reader)
+}
+
+/// Like [`solve_reader`], but parses the input with `parse`, e.g. to take
+/// options of the day's own binary into account.
+pub fn solve_with<S: Solution>(
+    reader: &mut dyn BufRead,
+    visualize: bool,
+    parse: impl FnOnce(&mut dyn BufRead) -> Result<S::Input, InputError>,
+) -> Result<DayResult, InputError> {
+    let (input, parse_time) = timed(|| parse(reader));
+    run::<S>(&input?, parse_time, visualize)
+}
+
+fn run<S: Solution>(
+    input: &S::Input,
+    parse_time: Duration,
+    visualize: bool,
+) -> Result<DayResult, InputError> {
+    let (part1, part1_time) = timed(|| S::part1(input).to_string());
+    let (part2, part2_time) = timed(|| S::part2(input));
+    let part2 = part2
+        .map_err(|err| InputError::Unsolved { part: 2, err })?
+        .to_string();
+
+    Ok(DayResult {
+        day: S::DAY,
+        parse_time,
+        parts: [
+            PartResult {
+                part: 1,
+                answer: part1,
+                elapsed: part1_time,
+            },
+            PartResult {
+                part: 2,
+                answer: part2,
+                elapsed: part2_time,
+            },
+        ],
+        visualization: if visualize { S::visualize(input) } else { None },
+    })
+}
+
+/// Median, minimum and maximum of repeated timings of one stage.
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn of(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+        Stats {
+            median: samples[samples.len() / 2],
+            min: samples[0],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DayBench {
+    pub day: u8,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+/// Runs parse, part 1 and part 2 of `S` `iterations` times (at least once),
+/// timing each stage separately.
+pub fn bench<S: Solution>(contents: &str, iterations: usize) -> Result<DayBench, ParseError> {
+    let iterations = iterations.max(1);
+    let mut samples = [const { Vec::new() }; 3];
+    for _ in 0..iterations {
+        let (input, parse_time) = timed(|| S::parse(black_box(contents)));
+        let input = input?;
+        let (_, part1_time) = timed(|| black_box(S::part1(&input)));
+        let (_, part2_time) = timed(|| black_box(S::part2(&input)));
+
+        for (samples, time) in samples.iter_mut().zip([parse_time, part1_time, part2_time]) {
+            samples.push(time);
+        }
+    }
+
+    let [parse, part1, part2] = samples.map(Stats::of);
+    Ok(DayBench {
+        day: S::DAY,
+        iterations,
+        parse,
+        part1,
+        part2,
+    })
+}
+
+/// Type-erased entry for a [`Solution`], so the runner can keep every day in
+/// one table.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    pub solve: fn(&mut dyn BufRead, bool) -> Result<DayResult, InputError>,
+    pub bench: fn(&str, usize) -> Result<DayBench, ParseError>,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Day {
+        Day {
+            number: S::DAY,
+            solve: solve_reader::<S>,
+            bench: bench::<S>,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_lines_has_one_record_per_part() {
+        let part = |part, answer: &str, micros| PartResult {
+            part,
+            answer: answer.to_owned(),
+            elapsed: Duration::from_micros(micros),
+        };
+        let result = DayResult {
+            day: 6,
+            parse_time: Duration::ZERO,
+            parts: [part(1, "41", 1500), part(2, "Loop Detected", 20)],
+            visualization: Some(String::from("#.^")),
+        };
+
+        assert_eq!(
+            result.json_lines(),
+            "{\"day\":6,\"part\":1,\"answer\":\"41\",\"elapsed_ms\":1.5}\n\
+             {\"day\":6,\"part\":2,\"answer\":\"Loop Detected\",\"elapsed_ms\":0.02}"
+        );
+    }
+}
@@ -0,0 +1,312 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// What the parser ran into instead of the expected token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Found {
+    Token(String),
+    EndOfLine,
+    EndOfInput,
+}
+
+impl Display for Found {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Found::Token(token) => write!(f, "`{token}`"),
+            Found::EndOfLine => write!(f, "end of line"),
+            Found::EndOfInput => write!(f, "end of input"),
+        }
+    }
+}
+
+/// A malformed puzzle input. Lines and columns start at 1, columns count
+/// characters rather than bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: Found,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>, found: Found) -> Self {
+        ParseError {
+            line,
+            column,
+            expected: expected.into(),
+            found,
+        }
+    }
+
+    /// Error for input that ends after `lines` lines although more was expected.
+    pub fn end_of_input(lines: usize, expected: impl Into<String>) -> Self {
+        ParseError::new(lines + 1, 1, expected, Found::EndOfInput)
+    }
+
+    /// Renders the error for the terminal: `name:line:column`, the message and
+    /// the offending line of `source` with a marker under the column.
+    pub fn render(&self, name: &str, source: &str) -> String {
+        let mut rendered = format!(
+            "{name}:{}:{}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        );
+        // Line 0 doesn't exist, so there is nothing to point at
+        let line = self
+            .line
+            .checked_sub(1)
+            .and_then(|index| source.lines().nth(index));
+        if let Some(line) = line {
+            let gutter = self.line.to_string().len();
+            // Keep tabs so the marker lines up with the offending character
+            let indent: String = line
+                .chars()
+                .take(self.column.saturating_sub(1))
+                .map(|char| if char == '\t' { '\t' } else { ' ' })
+                .collect();
+            rendered += &format!(
+                "\n{:gutter$} |\n{} | {line}\n{:gutter$} | {indent}^",
+                "", self.line, ""
+            );
+        }
+        rendered
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// The lines of `input` together with their (1-based) line numbers.
+pub fn lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.lines().enumerate().map(|(i, line)| (i + 1, line))
+}
+
+/// Walks over a single line of input, keeping track of the column so errors
+/// can point at the offending token.
+#[derive(Debug, Clone)]
+pub struct Cursor<'a> {
+    line: usize,
+    text: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(line: usize, text: &'a str) -> Self {
+        Cursor {
+            line,
+            text,
+            rest: text,
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.text[..self.text.len() - self.rest.len()]
+            .chars()
+            .count()
+            + 1
+    }
+
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    /// An error at the current column, reporting the word that starts here.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let found = match self.rest.chars().next() {
+            None => Found::EndOfLine,
+            Some(char) if char.is_whitespace() => Found::Token(char.to_string()),
+            Some(_) => Found::Token(
+                self.rest
+                    .split(char::is_whitespace)
+                    .next()
+                    .unwrap_or_default()
+                    .to_owned(),
+            ),
+        };
+        ParseError::new(self.line, self.column(), expected, found)
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest.chars().next()
+    }
+
+    pub fn next_char(&mut self) -> Option<char> {
+        let char = self.peek()?;
+        self.rest = &self.rest[char.len_utf8()..];
+        Some(char)
+    }
+
+    /// Consumes characters as long as they match `predicate`.
+    pub fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let end = self
+            .rest
+            .find(|char: char| !predicate(char))
+            .unwrap_or(self.rest.len());
+        let (taken, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        taken
+    }
+
+    /// Consumes `literal` if the rest of the line starts with it.
+    pub fn eat(&mut self, literal: &str) -> bool {
+        match self.rest.strip_prefix(literal) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn expect(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.eat(literal) {
+            Ok(())
+        } else {
+            Err(self.error(format!("`{literal}`")))
+        }
+    }
+
+    /// Skips any whitespace, returning whether there was some.
+    pub fn skip_whitespace(&mut self) -> bool {
+        let trimmed = self.rest.trim_start();
+        let skipped = trimmed.len() != self.rest.len();
+        self.rest = trimmed;
+        skipped
+    }
+
+    /// An optionally signed decimal number.
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let sign_len = usize::from(self.rest.starts_with(['-', '+']));
+        let digits = self.rest[sign_len..]
+            .find(|char: char| !char.is_ascii_digit())
+            .unwrap_or(self.rest.len() - sign_len);
+        if digits == 0 {
+            return Err(self.error("a number"));
+        }
+
+        let (number, rest) = self.rest.split_at(sign_len + digits);
+        let number = number.parse().map_err(|_| self.error("a number"))?;
+        self.rest = rest;
+        Ok(number)
+    }
+
+    /// Makes sure nothing but whitespace is left on the line.
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if self.is_at_end() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_points_at_the_column() {
+        let source = "47|53\n97,13\n";
+        let mut cursor = Cursor::new(2, source.lines().nth(1).unwrap());
+        let _: u32 = cursor.number().unwrap();
+        let err = cursor.expect("|").unwrap_err();
+        assert_eq!(
+            err.render("<stdin>", source),
+            "<stdin>:2:3: expected `|`, found `,13`\n  |\n2 | 97,13\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn render_keeps_tabs_and_widens_the_gutter() {
+        let source = "\n".repeat(9) + "\tab\n";
+        let err = ParseError::new(10, 3, "a digit", Found::Token(String::from("b")));
+        assert_eq!(
+            err.render("input.txt", &source),
+            "input.txt:10:3: expected a digit, found `b`\n   |\n10 | \tab\n   | \t ^"
+        );
+
+        // Errors past the last line have nothing to point at
+        let err = ParseError::end_of_input(10, "another line");
+        assert_eq!(
+            err.render("input.txt", &source),
+            "input.txt:11:1: expected another line, found end of input"
+        );
+    }
+
+    #[test]
+    fn render_without_line_or_column() {
+        let err = ParseError::new(0, 1, "a digit", Found::EndOfInput);
+        assert_eq!(
+            err.render("input.txt", "1\n"),
+            "input.txt:0:1: expected a digit, found end of input"
+        );
+        let err = ParseError::new(1, 0, "a digit", Found::EndOfLine);
+        assert_eq!(
+            err.render("input.txt", "x\n"),
+            "input.txt:1:0: expected a digit, found end of line\n  |\n1 | x\n  | ^"
+        );
+    }
+
+    #[test]
+    fn numbers_with_signs() {
+        let mut cursor = Cursor::new(1, "-12 +7 x");
+        assert_eq!(cursor.number::<i32>(), Ok(-12));
+        cursor.skip_whitespace();
+        assert_eq!(cursor.number::<i32>(), Ok(7));
+        cursor.skip_whitespace();
+        let err = cursor.number::<i32>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 8: expected a number, found `x`"
+        );
+
+        // A sign alone, an out of range value and a negative unsigned number
+        // are errors at the start of the number, which stays unconsumed
+        assert_eq!(Cursor::new(1, "-,").number::<i32>().unwrap_err().column, 1);
+        let mut cursor = Cursor::new(1, "300,1");
+        let err = cursor.number::<u8>().unwrap_err();
+        assert_eq!(err.found, Found::Token(String::from("300,1")));
+        assert_eq!(cursor.rest(), "300,1");
+        assert!(Cursor::new(1, "-5").number::<u32>().is_err());
+    }
+
+    #[test]
+    fn columns_count_characters() {
+        let mut cursor = Cursor::new(3, "äö  x");
+        cursor.next_char();
+        assert_eq!(cursor.column(), 2);
+        assert_eq!(cursor.error("x").found, Found::Token(String::from("ö")));
+        cursor.next_char();
+        // Whitespace is reported on its own, the word after it in full
+        assert_eq!(cursor.error("x").found, Found::Token(String::from(" ")));
+        cursor.skip_whitespace();
+        assert_eq!(cursor.column(), 5);
+        assert_eq!(
+            cursor.end().unwrap_err().to_string(),
+            "line 3, column 5: expected end of line, found `x`"
+        );
+        cursor.next_char();
+        assert_eq!(cursor.error("x").found, Found::EndOfLine);
+    }
+}
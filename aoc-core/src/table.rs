@@ -0,0 +1,36 @@
+use std::fmt::Write;
+
+/// Lays out `rows` in columns below `header`, separated by two spaces.
+/// Columns flagged in `right_aligned` are padded on the left, which suits
+/// numbers. Every line ends with a newline.
+pub fn render<const N: usize>(
+    header: [&str; N],
+    rows: &[[String; N]],
+    right_aligned: [bool; N],
+) -> String {
+    let mut widths = header.map(|column| column.chars().count());
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    let header = header.map(String::from);
+    for row in std::iter::once(&header).chain(rows) {
+        let mut line = String::new();
+        for (i, cell) in row.iter().enumerate() {
+            if i > 0 {
+                line.push_str("  ");
+            }
+            let width = widths[i];
+            if right_aligned[i] {
+                write!(line, "{cell:>width$}").unwrap();
+            } else {
+                write!(line, "{cell:width$}").unwrap();
+            }
+        }
+        writeln!(table, "{}", line.trim_end()).unwrap();
+    }
+    table
+}
@@ -0,0 +1,325 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A position or offset on the plane, `y` grows downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Point<T> {
+        Point { x, y }
+    }
+}
+
+impl Point<usize> {
+    pub fn signed(self) -> Point<isize> {
+        Point::new(self.x as isize, self.y as isize)
+    }
+
+    /// Moves the point by `delta`, returning `None` if either coordinate
+    /// would become negative.
+    pub fn checked_add_signed(self, delta: Point<isize>) -> Option<Point<usize>> {
+        Some(Point::new(
+            self.x.checked_add_signed(delta.x)?,
+            self.y.checked_add_signed(delta.y)?,
+        ))
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Point<T> {
+        Point { x, y }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+/// Scales both coordinates.
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, factor: T) -> Point<T> {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Point<T> {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Point<T>) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Point<T>) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// One of the four orthogonal directions. The discriminants follow the
+/// clockwise order of [`Direction::ALL`], so a direction can index arrays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum Direction {
+    Up = 0,
+    Right = 1,
+    Down = 2,
+    Left = 3,
+}
+
+impl Direction {
+    /// Every direction, clockwise starting with up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn delta(self) -> Point<isize> {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn from_delta(delta: Point<isize>) -> Option<Direction> {
+        Direction::ALL
+            .into_iter()
+            .find(|direction| direction.delta() == delta)
+    }
+
+    pub fn turned_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turned_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reversed(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The two directions at a right angle to this one.
+    pub fn orthogonal(self) -> [Direction; 2] {
+        [self.turned_left(), self.turned_right()]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+
+    /// Parses the arrows used by the puzzles (`^`, `>`, `v` and `<`).
+    pub fn from_char(char: char) -> Option<Direction> {
+        match char {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = char;
+
+    fn try_from(char: char) -> Result<Direction, char> {
+        Direction::from_char(char).ok_or(char)
+    }
+}
+
+impl From<Direction> for char {
+    fn from(direction: Direction) -> char {
+        direction.to_char()
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+/// One of the eight orthogonal and diagonal directions, clockwise starting
+/// with up like [`ADJACENT`](crate::ADJACENT).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum Direction8 {
+    Up = 0,
+    UpRight = 1,
+    Right = 2,
+    DownRight = 3,
+    Down = 4,
+    DownLeft = 5,
+    Left = 6,
+    UpLeft = 7,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    pub fn delta(self) -> Point<isize> {
+        crate::ADJACENT[self as usize]
+    }
+
+    pub fn reversed(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Direction8::Up => "up",
+            Direction8::UpRight => "up-right",
+            Direction8::Right => "right",
+            Direction8::DownRight => "down-right",
+            Direction8::Down => "down",
+            Direction8::DownLeft => "down-left",
+            Direction8::Left => "left",
+            Direction8::UpLeft => "up-left",
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Direction8 {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+impl Display for Direction8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_arithmetic() {
+        let mut point = Point::new(3, -2);
+        assert_eq!(point + Point::new(1, 5), Point::new(4, 3));
+        assert_eq!(point - Point::new(1, 5), Point::new(2, -7));
+        assert_eq!(point * 3, Point::new(9, -6));
+        assert_eq!(-point, Point::new(-3, 2));
+        point += Point::new(1, 1);
+        assert_eq!(point, Point::new(4, -1));
+        point -= Point::new(4, 4);
+        assert_eq!(point, Point::new(0, -5));
+        assert_eq!(Point::from((1, 2)).to_string(), "(1, 2)");
+
+        let origin = Point::new(0_usize, 2);
+        assert_eq!(
+            origin.checked_add_signed(Point::new(1, -2)),
+            Some(Point::new(1, 0))
+        );
+        assert_eq!(origin.checked_add_signed(Point::new(-1, 0)), None);
+        assert_eq!(origin.checked_add_signed(Point::new(0, -3)), None);
+        assert_eq!(origin.signed(), Point::new(0, 2));
+    }
+
+    #[test]
+    fn directions_turn_clockwise() {
+        assert_eq!(Direction::Up.turned_right(), Direction::Right);
+        assert_eq!(Direction::Left.turned_right(), Direction::Up);
+        assert_eq!(Direction::Up.turned_left(), Direction::Left);
+        assert_eq!(Direction::Down.reversed(), Direction::Up);
+        assert_eq!(
+            Direction::Right.orthogonal(),
+            [Direction::Up, Direction::Down]
+        );
+        for direction in Direction::ALL {
+            assert_eq!(direction.turned_left().turned_right(), direction);
+            assert_eq!(direction.reversed().delta(), -direction.delta());
+            assert_eq!(Direction::from_delta(direction.delta()), Some(direction));
+            assert_eq!(Direction::from_char(direction.to_char()), Some(direction));
+            assert_ne!(direction.is_horizontal(), direction.is_vertical());
+        }
+        assert_eq!(Direction::from_delta(Point::new(1, 1)), None);
+        assert_eq!(Direction::from_char('x'), None);
+        assert_eq!(Direction::try_from('v'), Ok(Direction::Down));
+        assert_eq!(char::from(Direction::Left), '<');
+    }
+
+    #[test]
+    fn diagonal_directions() {
+        assert_eq!(Direction8::UpRight.delta(), Point::new(1, -1));
+        assert_eq!(Direction8::DownLeft.reversed(), Direction8::UpRight);
+        assert!(Direction8::UpLeft.is_diagonal());
+        assert!(!Direction8::Left.is_diagonal());
+        for direction in Direction8::ALL {
+            assert_eq!(direction.reversed().delta(), -direction.delta());
+        }
+        for direction in Direction::ALL {
+            let direction8 = Direction8::from(direction);
+            assert_eq!(direction8.delta(), direction.delta());
+            assert!(!direction8.is_diagonal());
+        }
+        assert_eq!(Direction8::DownRight.to_string(), "down-right");
+    }
+}
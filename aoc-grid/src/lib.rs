@@ -0,0 +1,384 @@
+pub mod geometry;
+
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use aoc_core::parse::{self, Found, ParseError};
+pub use geometry::{Direction, Direction8, Point};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Offsets of all eight neighbors, clockwise starting with up.
+pub const ADJACENT: [Point<isize>; 8] = [
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(1, 0),
+    Point::new(1, 1),
+    Point::new(0, 1),
+    Point::new(-1, 1),
+    Point::new(-1, 0),
+    Point::new(-1, -1),
+];
+
+/// A rectangular grid stored row by row in a flat `Vec`, with `(0, 0)` in the
+/// top left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "{width}x{height} grid needs {} cells",
+            width * height
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_vec(width, height, vec![value; width * height])
+    }
+
+    /// Parses one row per line and one cell per character. `cell` returns
+    /// `None` for characters that are not part of the grid, which is reported
+    /// as a [`ParseError`] expecting `expected`. Every line needs as many
+    /// characters as the first one.
+    pub fn parse(
+        text: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        Grid::parse_cells(
+            text,
+            expected,
+            |line| {
+                line.char_indices()
+                    .map(move |(i, char)| &line[i..i + char.len_utf8()])
+            },
+            |char| cell(char.chars().next().unwrap()),
+        )
+    }
+
+    /// Like [`Grid::parse`], but with one cell per grapheme cluster, so a
+    /// letter followed by a combining mark (`U\u{308}` for `Ü`) is a single
+    /// cell just like the precomposed letter.
+    pub fn parse_graphemes(
+        text: &str,
+        expected: &str,
+        cell: impl FnMut(&str) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        Grid::parse_cells(text, expected, |line| line.graphemes(true), cell)
+    }
+
+    /// The rows are split into cells by `split`. Error columns count
+    /// characters, not cells.
+    fn parse_cells<'a, I: Iterator<Item = &'a str>>(
+        text: &'a str,
+        expected: &str,
+        split: impl Fn(&'a str) -> I,
+        mut cell: impl FnMut(&'a str) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for (number, line) in parse::lines(text) {
+            let mut line_width = 0;
+            let mut column = 1;
+            for text in split(line) {
+                if width.is_some_and(|width| line_width == width) {
+                    return Err(ParseError::new(
+                        number,
+                        column,
+                        "end of line",
+                        Found::Token(text.to_owned()),
+                    ));
+                }
+                let value = cell(text).ok_or_else(|| {
+                    ParseError::new(number, column, expected, Found::Token(text.to_owned()))
+                })?;
+                cells.push(value);
+                line_width += 1;
+                column += text.chars().count();
+            }
+            match width {
+                None => width = Some(line_width),
+                Some(width) if line_width < width => {
+                    return Err(ParseError::new(number, column, expected, Found::EndOfLine))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid::from_vec(width, height, cells)),
+            _ => Err(ParseError::new(1, 1, expected, Found::EndOfInput)),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Point<usize>) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    fn index_of(&self, position: Point<usize>) -> usize {
+        position.y * self.width + position.x
+    }
+
+    pub fn get(&self, position: Point<usize>) -> Option<&T> {
+        if self.contains(position) {
+            Some(&self.cells[self.index_of(position)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Point<usize>) -> Option<&mut T> {
+        if self.contains(position) {
+            let index = self.index_of(position);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    /// Moves `position` by `delta`, returning `None` if that leaves the grid.
+    pub fn offset(&self, position: Point<usize>, delta: Point<isize>) -> Option<Point<usize>> {
+        let position = position.checked_add_signed(delta)?;
+        self.contains(position).then_some(position)
+    }
+
+    /// The neighbor of `position` in `direction`, if it is inside the grid.
+    pub fn step(&self, position: Point<usize>, direction: Direction) -> Option<Point<usize>> {
+        self.offset(position, direction.delta())
+    }
+
+    /// The up to four orthogonal neighbors of `position` inside the grid.
+    pub fn neighbors4(&self, position: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The up to eight orthogonal and diagonal neighbors of `position` inside
+    /// the grid.
+    pub fn neighbors8(&self, position: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        ADJACENT
+            .into_iter()
+            .filter_map(move |delta| self.offset(position, delta))
+    }
+
+    /// Every position of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` keeps `chunks` happy for grids without columns
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Position of the first cell (row by row) matching `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Point<usize>> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_vec(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// The grid turned a quarter clockwise.
+    pub fn rotated_right(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|x| (0..self.height).rev().map(move |y| Point::new(x, y)))
+            .map(|position| self[position].clone())
+            .collect();
+        Grid::from_vec(self.height, self.width, cells)
+    }
+
+    /// The grid mirrored left to right.
+    pub fn flipped_horizontally(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = self
+            .rows()
+            .flat_map(|row| row.iter().rev().cloned())
+            .collect();
+        Grid::from_vec(self.width, self.height, cells)
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point<usize>) -> &T {
+        assert!(
+            self.contains(position),
+            "{position} is outside of the {}x{} grid",
+            self.width,
+            self.height
+        );
+        &self.cells[self.index_of(position)]
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, position: Point<usize>) -> &mut T {
+        assert!(
+            self.contains(position),
+            "{position} is outside of the {}x{} grid",
+            self.width,
+            self.height
+        );
+        let index = self.index_of(position);
+        &mut self.cells[index]
+    }
+}
+
+/// Renders one line per row, without a trailing newline.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters(text: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse(text, "a letter", |char| {
+            char.is_alphabetic().then_some(char)
+        })
+    }
+
+    #[test]
+    fn rows_need_the_same_width() {
+        let grid = letters("ab\ncd\nef\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[Point::new(1, 2)], 'f');
+        assert_eq!(grid.to_string(), "ab\ncd\nef");
+
+        let err = letters("ab\nc\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: expected a letter, found end of line"
+        );
+        let err = letters("ab\nabc\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected end of line, found `c`"
+        );
+        let err = letters("ab\na1\n").unwrap_err();
+        assert_eq!(
+            (err.column, err.found),
+            (2, Found::Token(String::from("1")))
+        );
+        assert_eq!(letters("").unwrap_err().found, Found::EndOfInput);
+    }
+
+    #[test]
+    fn grapheme_cells_keep_character_columns() {
+        let grapheme = |text: &str| -> Option<String> { Some(text.to_owned()) };
+        // The second Ü is a U followed by a combining diaeresis, two
+        // characters in one cell
+        let grid = Grid::parse_graphemes("Üx\nU\u{308}y\n", "a letter", grapheme).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid[Point::new(0, 1)], "U\u{308}");
+
+        let err = Grid::parse_graphemes("Üx\nU\u{308}yz\n", "a letter", grapheme).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 4: expected end of line, found `z`"
+        );
+    }
+
+    #[test]
+    fn steps_stay_inside() {
+        let grid = Grid::new(3, 2, 0);
+        let corner = Point::new(2, 1);
+        assert_eq!(grid.step(corner, Direction::Up), Some(Point::new(2, 0)));
+        assert_eq!(grid.step(corner, Direction::Right), None);
+        assert_eq!(grid.step(corner, Direction::Down), None);
+        assert_eq!(
+            grid.offset(corner, Point::new(-2, -1)),
+            Some(Point::new(0, 0))
+        );
+        assert_eq!(grid.offset(corner, Point::new(-3, 0)), None);
+        assert_eq!(grid.offset(Point::new(0, 0), Point::new(0, -1)), None);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+    }
+
+    #[test]
+    fn neighbors_at_the_edges() {
+        let grid = Grid::new(3, 3, 0);
+        let corner: Vec<Point<usize>> = grid.neighbors4(Point::new(0, 0)).collect();
+        assert_eq!(corner, [Point::new(1, 0), Point::new(0, 1)]);
+        let corner: Vec<Point<usize>> = grid.neighbors8(Point::new(0, 0)).collect();
+        assert_eq!(
+            corner,
+            [Point::new(1, 0), Point::new(1, 1), Point::new(0, 1)]
+        );
+        let edge: Vec<Point<usize>> = grid.neighbors4(Point::new(2, 1)).collect();
+        assert_eq!(edge, [Point::new(2, 0), Point::new(2, 2), Point::new(1, 1)]);
+
+        assert_eq!(grid.neighbors4(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbors8(Point::new(2, 1)).count(), 5);
+    }
+
+    #[test]
+    fn rotates_and_flips() {
+        let grid = letters("ab\ncd\nef\n").unwrap();
+        let rotated = grid.rotated_right();
+        assert_eq!((rotated.width(), rotated.height()), (3, 2));
+        assert_eq!(rotated.to_string(), "eca\nfdb");
+        let turned_around = grid
+            .rotated_right()
+            .rotated_right()
+            .rotated_right()
+            .rotated_right();
+        assert_eq!(turned_around, grid);
+
+        assert_eq!(grid.flipped_horizontally().to_string(), "ba\ndc\nfe");
+    }
+}
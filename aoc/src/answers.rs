@@ -0,0 +1,339 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs,
+    io::{self, BufRead, Read},
+    path::Path,
+};
+
+use aoc_core::{
+    parse::{self, Cursor},
+    table, ParseError,
+};
+
+const HEADER: &str = "# day\tpart\tinput\tanswer";
+
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Computes the FNV-1a hash of everything read through it, so answers for
+/// different inputs (e.g. the example and the real input) can be stored side
+/// by side without keeping a streamed input around to hash it.
+pub struct HashingReader<R> {
+    inner: R,
+    hash: u64,
+    /// Why bytes consumed through [`BufRead::consume`] couldn't be hashed,
+    /// which can't return the error itself.
+    error: Option<io::Error>,
+}
+
+impl<R: BufRead> HashingReader<R> {
+    pub fn new(inner: R) -> HashingReader<R> {
+        HashingReader {
+            inner,
+            hash: FNV_OFFSET,
+            error: None,
+        }
+    }
+
+    /// Reads whatever is left and returns the hash of the whole input, or
+    /// the error that left some of it unhashed.
+    pub fn finish(mut self) -> io::Result<u64> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+        io::copy(&mut self, &mut io::sink())?;
+        Ok(self.hash)
+    }
+}
+
+impl<R: BufRead> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = self.inner.read(buf)?;
+        self.hash = fnv1a(self.hash, &buf[..len]);
+        Ok(len)
+    }
+}
+
+impl<R: BufRead> BufRead for HashingReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amount: usize) {
+        // The bytes being consumed are still buffered, so this doesn't read
+        match self.inner.fill_buf() {
+            Ok(buffer) => self.hash = fnv1a(self.hash, &buffer[..amount]),
+            Err(err) => {
+                self.error.get_or_insert(err);
+            }
+        }
+        self.inner.consume(amount);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AnswerKey {
+    pub day: u8,
+    pub part: u8,
+    pub input: u64,
+}
+
+/// Known good answers, stored as tab separated `day part input answer` lines
+/// with the input hash written as 16 hex digits.
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: BTreeMap<AnswerKey, String>,
+}
+
+impl Answers {
+    pub fn parse(contents: &str) -> Result<Answers, ParseError> {
+        let mut answers = BTreeMap::new();
+        for (number, line) in parse::lines(contents) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut cursor = Cursor::new(number, line);
+            let day = cursor.number()?;
+            cursor.expect("\t")?;
+            let part = cursor.number()?;
+            cursor.expect("\t")?;
+            let start = cursor.clone();
+            let hex = cursor.take_while(|char| char.is_ascii_hexdigit());
+            let input = match u64::from_str_radix(hex, 16) {
+                Ok(input) if hex.len() == 16 => input,
+                _ => return Err(start.error("a 16 digit input hash")),
+            };
+            cursor.expect("\t")?;
+            if cursor.is_at_end() {
+                return Err(cursor.error("an answer"));
+            }
+
+            answers.insert(AnswerKey { day, part, input }, cursor.rest().to_owned());
+        }
+        Ok(Answers { answers })
+    }
+
+    pub fn get(&self, key: AnswerKey) -> Option<&str> {
+        self.answers.get(&key).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, key: AnswerKey, answer: String) {
+        self.answers.insert(key, answer);
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut contents = format!("{HEADER}\n");
+        for (key, answer) in &self.answers {
+            writeln!(
+                contents,
+                "{}\t{}\t{:016x}\t{answer}",
+                key.day, key.part, key.input
+            )
+            .unwrap();
+        }
+        fs::write(path, contents)
+    }
+}
+
+/// An answer of the current run compared to the recorded one.
+#[derive(Debug)]
+pub struct Check {
+    pub key: AnswerKey,
+    pub expected: Option<String>,
+    pub actual: String,
+}
+
+impl Check {
+    pub fn matches(&self) -> bool {
+        self.expected.as_deref() == Some(self.actual.as_str())
+    }
+}
+
+/// Lists every answer that differs from (or is missing in) the answers file,
+/// with the recorded answer prefixed by `-` and the new one by `+`, followed
+/// by a summary line.
+pub fn verification_report(checks: &[Check]) -> String {
+    let differing: Vec<&Check> = checks.iter().filter(|check| !check.matches()).collect();
+    let mismatches = differing
+        .iter()
+        .filter(|check| check.expected.is_some())
+        .count();
+    let unrecorded = differing.len() - mismatches;
+
+    let mut report = String::new();
+    if !differing.is_empty() {
+        let rows: Vec<[String; 5]> = differing
+            .iter()
+            .map(|check| {
+                [
+                    check.key.day.to_string(),
+                    check.key.part.to_string(),
+                    format!("{:016x}", check.key.input),
+                    match &check.expected {
+                        Some(expected) => format!("- {expected}"),
+                        None => String::from("  (not recorded)"),
+                    },
+                    format!("+ {}", check.actual),
+                ]
+            })
+            .collect();
+        report += &table::render(
+            ["Day", "Part", "Input", "Expected", "Actual"],
+            &rows,
+            [true, true, false, false, false],
+        );
+    }
+
+    write!(
+        report,
+        "{} of {} answers match, {mismatches} different, {unrecorded} not recorded",
+        checks.len() - differing.len(),
+        checks.len()
+    )
+    .unwrap();
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash(contents: &str) -> u64 {
+        HashingReader::new(contents.as_bytes()).finish().unwrap()
+    }
+
+    #[test]
+    fn hash_covers_streamed_and_unread_input() {
+        let contents = "3   4\n4   3\n2   5\n";
+        // FNV-1a of the empty input is its offset basis
+        assert_eq!(hash(""), 0xcbf29ce484222325);
+        assert_ne!(hash(contents), hash("3   4\n4   3\n"));
+
+        // Lines consumed through `BufRead`, bytes through `Read` and the
+        // rest left unread all count
+        let mut reader = HashingReader::new(io::BufReader::with_capacity(4, contents.as_bytes()));
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        let mut bytes = [0; 3];
+        reader.read_exact(&mut bytes).unwrap();
+        assert_eq!(reader.finish().unwrap(), hash(contents));
+    }
+
+    /// Hands out its bytes once, then fails every further `fill_buf`.
+    struct FailingRefill<'a> {
+        bytes: &'a [u8],
+        filled: bool,
+    }
+
+    impl Read for FailingRefill<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.bytes.read(buf)
+        }
+    }
+
+    impl BufRead for FailingRefill<'_> {
+        fn fill_buf(&mut self) -> io::Result<&[u8]> {
+            if self.filled {
+                return Err(io::Error::other("refill failed"));
+            }
+            self.filled = true;
+            Ok(self.bytes)
+        }
+
+        fn consume(&mut self, amount: usize) {
+            self.bytes = &self.bytes[amount..];
+        }
+    }
+
+    #[test]
+    fn bytes_that_could_not_be_hashed_fail_the_hash() {
+        let mut reader = HashingReader::new(FailingRefill {
+            bytes: b"3   4\n",
+            filled: false,
+        });
+        let len = reader.fill_buf().unwrap().len();
+        reader.consume(len);
+        assert_eq!(reader.finish().unwrap_err().to_string(), "refill failed");
+    }
+
+    fn key(day: u8, part: u8) -> AnswerKey {
+        AnswerKey {
+            day,
+            part,
+            input: 0xbe032f7e1fe2c15e,
+        }
+    }
+
+    #[test]
+    fn parses_answers_and_skips_comments() {
+        let answers =
+            Answers::parse("# day\tpart\tinput\tanswer\n\n1\t2\tbe032f7e1fe2c15e\tNo Tree\n")
+                .unwrap();
+        assert_eq!(answers.get(key(1, 2)), Some("No Tree"));
+        assert_eq!(answers.get(key(1, 1)), None);
+
+        let err = Answers::parse("1\t1\tbe032f7e\t11\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 5: expected a 16 digit input hash, found `be032f7e`"
+        );
+        let err = Answers::parse("1\t1\tbe032f7e1fe2c15e\t\n").unwrap_err();
+        assert_eq!(err.expected, "an answer");
+    }
+
+    #[test]
+    fn saved_answers_read_back() {
+        let mut answers = Answers::default();
+        answers.insert(key(2, 1), String::from("2"));
+        answers.insert(key(1, 1), String::from("11"));
+        answers.insert(key(1, 1), String::from("12"));
+
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.tsv", std::process::id()));
+        answers.save(&path).unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            contents,
+            "# day\tpart\tinput\tanswer\n\
+             1\t1\tbe032f7e1fe2c15e\t12\n\
+             2\t1\tbe032f7e1fe2c15e\t2\n"
+        );
+        let read_back = Answers::parse(&contents).unwrap();
+        assert_eq!(read_back.answers, answers.answers);
+    }
+
+    #[test]
+    fn report_lists_differences_only() {
+        let check = |part, expected: Option<&str>, actual: &str| Check {
+            key: key(1, part),
+            expected: expected.map(str::to_owned),
+            actual: actual.to_owned(),
+        };
+        let checks = [
+            check(1, Some("11"), "11"),
+            check(2, Some("31"), "32"),
+            check(3, None, "7"),
+        ];
+        let report = verification_report(&checks);
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(lines.len(), 4, "{report}");
+        assert!(lines[1].contains("- 31") && lines[1].contains("+ 32"));
+        assert!(lines[2].contains("(not recorded)") && lines[2].contains("+ 7"));
+        assert_eq!(
+            lines[3],
+            "1 of 3 answers match, 1 different, 1 not recorded"
+        );
+
+        assert_eq!(
+            verification_report(&checks[..1]),
+            "1 of 1 answers match, 0 different, 0 not recorded"
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use std::{collections::BTreeMap, fmt::Write, fs, io, path::Path, time::Duration};
+
+use aoc_core::{table, DayBench, Stats};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Timing {
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+}
+
+impl From<Stats> for Timing {
+    fn from(stats: Stats) -> Timing {
+        let nanos = |duration: Duration| duration.as_nanos() as u64;
+        Timing {
+            median_ns: nanos(stats.median),
+            min_ns: nanos(stats.min),
+            max_ns: nanos(stats.max),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayTimings {
+    pub iterations: usize,
+    pub parse: Timing,
+    pub part1: Timing,
+    pub part2: Timing,
+}
+
+impl DayTimings {
+    fn stages(&self) -> [(&'static str, Timing); 3] {
+        [
+            ("parse", self.parse),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+        ]
+    }
+}
+
+impl From<&DayBench> for DayTimings {
+    fn from(bench: &DayBench) -> DayTimings {
+        DayTimings {
+            iterations: bench.iterations,
+            parse: bench.parse.into(),
+            part1: bench.part1.into(),
+            part2: bench.part2.into(),
+        }
+    }
+}
+
+/// Timings of a benchmark run, saved as JSON so the next run can be compared
+/// against them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BenchResults {
+    pub days: BTreeMap<u8, DayTimings>,
+}
+
+impl BenchResults {
+    /// Reads earlier results, a missing file counts as no earlier results.
+    pub fn load(path: &Path) -> io::Result<BenchResults> {
+        match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(BenchResults::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, json + "\n")
+    }
+}
+
+/// Lists every stage of `current` next to its median in `previous`. Stages
+/// whose median grew by more than `threshold` percent are flagged, their
+/// number is returned together with the report.
+pub fn compare(current: &BenchResults, previous: &BenchResults, threshold: f64) -> (String, usize) {
+    let format = |nanos: u64| format!("{:.2?}", Duration::from_nanos(nanos));
+
+    let mut regressions = 0;
+    let mut rows = Vec::new();
+    for (&day, timings) in &current.days {
+        let previous = previous.days.get(&day).map(DayTimings::stages);
+        for (i, (stage, timing)) in timings.stages().into_iter().enumerate() {
+            let (previous_median, change) = match previous {
+                Some(previous) if previous[i].1.median_ns > 0 => {
+                    let before = previous[i].1.median_ns;
+                    let change = (timing.median_ns as f64 / before as f64 - 1.0) * 100.0;
+                    let mut change = format!("{change:+.1}%");
+                    if timing.median_ns as f64 > before as f64 * (1.0 + threshold / 100.0) {
+                        regressions += 1;
+                        change += " regression";
+                    }
+                    (format(before), change)
+                }
+                _ => (String::from("-"), String::new()),
+            };
+
+            rows.push([
+                day.to_string(),
+                stage.to_owned(),
+                format(timing.median_ns),
+                format(timing.min_ns),
+                format(timing.max_ns),
+                previous_median,
+                change,
+            ]);
+        }
+    }
+
+    let mut report = table::render(
+        ["Day", "Stage", "Median", "Min", "Max", "Previous", "Change"],
+        &rows,
+        [true, false, true, true, true, true, false],
+    );
+    write!(
+        report,
+        "{regressions} regressions above {threshold}% compared to the previous run"
+    )
+    .unwrap();
+    (report, regressions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timings(parse: u64, part1: u64, part2: u64) -> DayTimings {
+        let timing = |median_ns| Timing {
+            median_ns,
+            min_ns: median_ns / 2,
+            max_ns: median_ns * 2,
+        };
+        DayTimings {
+            iterations: 10,
+            parse: timing(parse),
+            part1: timing(part1),
+            part2: timing(part2),
+        }
+    }
+
+    #[test]
+    fn flags_medians_above_the_threshold() {
+        let previous = BenchResults {
+            days: BTreeMap::from([(1, timings(100, 100, 100)), (3, timings(0, 100, 100))]),
+        };
+        let current = BenchResults {
+            days: BTreeMap::from([
+                // Exactly at the threshold is still fine
+                (1, timings(110, 121, 50)),
+                // Not benchmarked before
+                (2, timings(100, 100, 100)),
+                // A zero median can't be compared with
+                (3, timings(500, 111, 100)),
+            ]),
+        };
+
+        let (report, regressions) = compare(&current, &previous, 10.0);
+        assert_eq!(regressions, 2, "{report}");
+        let lines: Vec<&str> = report.lines().collect();
+        assert!(lines[1].ends_with("+10.0%"), "{}", lines[1]);
+        assert!(lines[2].ends_with("+21.0% regression"), "{}", lines[2]);
+        assert!(lines[3].ends_with("-50.0%"), "{}", lines[3]);
+        assert!(lines[4].trim_end().ends_with('-'), "{}", lines[4]);
+        assert!(lines[7].trim_end().ends_with('-'), "{}", lines[7]);
+        assert!(lines[8].ends_with("+11.0% regression"), "{}", lines[8]);
+        assert_eq!(
+            lines[10],
+            "2 regressions above 10% compared to the previous run"
+        );
+
+        // Without earlier results nothing can regress
+        let (_, regressions) = compare(&current, &BenchResults::default(), 0.0);
+        assert_eq!(regressions, 0);
+    }
+}
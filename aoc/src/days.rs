@@ -0,0 +1,23 @@
+use aoc_core::Day;
+
+pub const DAYS: [Day; 15] = [
+    Day::of::<d1::Day1>(),
+    Day::of::<d2::Day2>(),
+    Day::of::<d3::Day3>(),
+    Day::of::<d4::Day4>(),
+    Day::of::<d5::Day5>(),
+    Day::of::<d6::Day6>(),
+    Day::of::<d7::Day7>(),
+    Day::of::<d8::Day8>(),
+    Day::of::<d9::Day9>(),
+    Day::of::<d10::Day10>(),
+    Day::of::<d11::Day11>(),
+    Day::of::<d12::Day12>(),
+    Day::of::<d13::Day13>(),
+    Day::of::<d14::Day14>(),
+    Day::of::<d15::Day15>(),
+];
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,178 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// Neither `AOC_SESSION` nor the session file provide a token.
+    MissingSession(Option<PathBuf>),
+    Http(ureq::Error),
+    Io(io::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::MissingSession(Some(path)) => write!(
+                f,
+                "no session token, set AOC_SESSION or write it to {}",
+                path.display()
+            ),
+            FetchError::MissingSession(None) => write!(f, "no session token, set AOC_SESSION"),
+            FetchError::Http(err) => write!(f, "download failed: {err}"),
+            FetchError::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<ureq::Error> for FetchError {
+    fn from(err: ureq::Error) -> FetchError {
+        FetchError::Http(err)
+    }
+}
+
+impl From<io::Error> for FetchError {
+    fn from(err: io::Error) -> FetchError {
+        FetchError::Io(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    Cached,
+}
+
+/// Where the session token is stored when it is not passed through the
+/// environment: `$XDG_CONFIG_HOME/aoc/session`, falling back to
+/// `~/.config/aoc/session`.
+pub fn session_file() -> Option<PathBuf> {
+    let config_dir = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+    Some(config_dir.join("aoc/session"))
+}
+
+/// Downloads puzzle inputs, using files that already exist as a cache.
+pub struct Fetcher {
+    pub base_url: String,
+    pub year: u16,
+    /// Only needed once something has to be downloaded.
+    pub session: Option<String>,
+}
+
+impl Fetcher {
+    pub fn input_url(&self, day: u8) -> String {
+        format!(
+            "{}/{}/day/{day}/input",
+            self.base_url.trim_end_matches('/'),
+            self.year
+        )
+    }
+
+    fn session(&self) -> Result<String, FetchError> {
+        if let Some(session) = &self.session {
+            return Ok(session.clone());
+        }
+
+        let path = session_file();
+        match path.as_deref().map(fs::read_to_string) {
+            Some(Ok(session)) if !session.trim().is_empty() => Ok(session.trim().to_owned()),
+            Some(Err(err)) if err.kind() != io::ErrorKind::NotFound => Err(err.into()),
+            _ => Err(FetchError::MissingSession(path)),
+        }
+    }
+
+    /// Stores the input of `day` at `path` unless that file already exists.
+    pub fn fetch(&self, day: u8, path: &Path) -> Result<Fetched, FetchError> {
+        if path.exists() {
+            return Ok(Fetched::Cached);
+        }
+
+        let session = self.session()?;
+        let input = ureq::get(&self.input_url(day))
+            .header("Cookie", &format!("session={session}"))
+            .header("User-Agent", "aoc-2024 input fetcher")
+            .call()?
+            .body_mut()
+            .read_to_string()?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // Write to a temporary file first so a failed write never leaves a
+        // truncated input behind that would be mistaken for a cached one
+        let partial = path.with_extension("partial");
+        fs::write(&partial, input)?;
+        fs::rename(&partial, path)?;
+        Ok(Fetched::Downloaded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use super::*;
+
+    /// Answers a single request with `body` and returns the request line and
+    /// headers it received.
+    fn stub_server(body: &'static str) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request: Vec<String> = BufReader::new(&stream)
+                .lines()
+                .map(Result::unwrap)
+                .take_while(|line| !line.is_empty())
+                .collect();
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+        (base_url, handle)
+    }
+
+    #[test]
+    fn downloads_once_and_then_uses_the_cache() {
+        let (base_url, server) = stub_server("3   4\n4   3\n");
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let path = dir.join("d1/inputs/input.txt");
+        let _ = fs::remove_dir_all(&dir);
+
+        let fetcher = Fetcher {
+            base_url,
+            year: 2024,
+            session: Some(String::from("secret")),
+        };
+        assert_eq!(fetcher.fetch(1, &path).unwrap(), Fetched::Downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n4   3\n");
+
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2024/day/1/input HTTP/1.1");
+        assert!(request
+            .iter()
+            .any(|header| header.eq_ignore_ascii_case("cookie: session=secret")));
+
+        // The stub server is gone, so this only succeeds without a request
+        assert_eq!(fetcher.fetch(1, &path).unwrap(), Fetched::Cached);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
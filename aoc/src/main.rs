@@ -0,0 +1,350 @@
+use std::{
+    env, fs, io,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use answers::{AnswerKey, Answers, Check, HashingReader};
+use aoc_core::{
+    cli::{InputArgs, OutputArgs},
+    InputError, ParseError,
+};
+use bench::BenchResults;
+use clap::{Parser, Subcommand};
+use fetch::{FetchError, Fetched, Fetcher};
+
+mod answers;
+mod bench;
+mod days;
+mod fetch;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one or more days, reading each input from `d<day>/inputs/input.txt`
+    Run {
+        /// A single day (`6`) or a range of days (`1..=15`, `1..16`)
+        #[arg(value_parser = parse_days)]
+        days: RangeInclusive<u8>,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        #[command(flatten)]
+        output: OutputArgs,
+
+        /// Store the answers in the answers file, keyed by day, part and input
+        #[arg(long, conflicts_with = "verify")]
+        record: bool,
+
+        /// Compare the answers with the answers file and list every difference
+        #[arg(long)]
+        verify: bool,
+
+        /// File holding the known good answers
+        #[arg(long, value_name = "PATH", default_value = "answers.tsv")]
+        answers: PathBuf,
+    },
+    /// Time parse, part 1 and part 2 of one or more days over many runs and
+    /// compare the medians with the previous benchmark
+    Bench {
+        /// A single day (`6`) or a range of days (`1..=15`, `1..16`)
+        #[arg(value_parser = parse_days)]
+        days: RangeInclusive<u8>,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// How often every stage is run
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+
+        /// File the results are compared with and saved to
+        #[arg(long, value_name = "PATH", default_value = "target/bench.json")]
+        output: PathBuf,
+
+        /// Slowdown of a median in percent that counts as a regression
+        #[arg(long, value_name = "PERCENT", default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Download the puzzle inputs of one or more days to `d<day>/inputs/input.txt`.
+    /// Inputs that were downloaded before are never fetched again. The session
+    /// token is read from `AOC_SESSION` or `~/.config/aoc/session`
+    Fetch {
+        /// A single day (`6`) or a range of days (`1..=15`, `1..16`)
+        #[arg(value_parser = parse_days)]
+        days: RangeInclusive<u8>,
+
+        #[arg(long, default_value_t = 2024)]
+        year: u16,
+
+        /// Server to download the inputs from
+        #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+}
+
+fn parse_days(days: &str) -> Result<RangeInclusive<u8>, String> {
+    let parse_day = |day: &str| {
+        day.trim()
+            .parse::<u8>()
+            .map_err(|_| format!("`{day}` is not a day"))
+    };
+
+    let range = if let Some((start, end)) = days.split_once("..=") {
+        parse_day(start)?..=parse_day(end)?
+    } else if let Some((start, end)) = days.split_once("..") {
+        let end = parse_day(end)?;
+        if end == 0 {
+            return Err(format!("`{days}` contains no days"));
+        }
+        parse_day(start)?..=end - 1
+    } else {
+        let day = parse_day(days)?;
+        day..=day
+    };
+
+    if range.is_empty() {
+        return Err(format!("`{days}` contains no days"));
+    }
+    if let Some(day) = range.clone().find(|&day| days::get(day).is_none()) {
+        return Err(format!("day {day} is not solved yet"));
+    }
+    Ok(range)
+}
+
+/// Reads the answers file, a missing file counts as one without answers.
+fn load_answers(path: &Path) -> Result<Answers, String> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+        Err(err) => return Err(format!("Unable to read {}: {err}", path.display())),
+    };
+    Answers::parse(&contents).map_err(|err| err.render(&path.display().to_string(), &contents))
+}
+
+/// Reads the input of day `number`, reporting a failure on stderr.
+fn read_input(number: u8, input: &InputArgs) -> Option<String> {
+    let day_dir = PathBuf::from(format!("d{number}"));
+    match input.read(&day_dir) {
+        Ok(contents) => Some(contents),
+        Err(err) => {
+            eprintln!(
+                "Day {number}: unable to read {}: {err}",
+                input.describe(&day_dir)
+            );
+            None
+        }
+    }
+}
+
+/// Reports a malformed input of day `number` on stderr.
+fn report_parse_error(number: u8, input: &InputArgs, contents: &str, err: ParseError) {
+    let day_dir = PathBuf::from(format!("d{number}"));
+    eprintln!(
+        "Day {number}: {}",
+        err.render(&input.describe(&day_dir), contents)
+    );
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            days,
+            input,
+            output,
+            record,
+            verify,
+            answers: answers_path,
+        } => {
+            if input.input.is_some() && days.start() != days.end() {
+                eprintln!("--input can only be used when running a single day");
+                return ExitCode::FAILURE;
+            }
+
+            let mut answers = if record || verify {
+                match load_answers(&answers_path) {
+                    Ok(answers) => answers,
+                    Err(err) => {
+                        eprintln!("{err}");
+                        return ExitCode::FAILURE;
+                    }
+                }
+            } else {
+                Answers::default()
+            };
+
+            let mut status = ExitCode::SUCCESS;
+            let mut checks = Vec::new();
+            for number in days {
+                let day = days::get(number).unwrap();
+                let day_dir = PathBuf::from(format!("d{number}"));
+                // The input is streamed into the day and hashed on the way
+                let solved = input
+                    .open(&day_dir)
+                    .map_err(InputError::from)
+                    .and_then(|reader| {
+                        let mut reader = HashingReader::new(reader);
+                        let result = (day.solve)(&mut reader, output.visualize() && !verify)?;
+                        Ok((result, reader.finish()?))
+                    });
+                let (result, input_hash) = match solved {
+                    Ok(solved) => solved,
+                    Err(InputError::Io(err)) => {
+                        eprintln!(
+                            "Day {number}: unable to read {}: {err}",
+                            input.describe(&day_dir)
+                        );
+                        status = ExitCode::FAILURE;
+                        continue;
+                    }
+                    Err(InputError::Parse(err)) => {
+                        eprintln!("Day {number}: {}", input.render_error(&day_dir, &err));
+                        status = ExitCode::FAILURE;
+                        continue;
+                    }
+                    // Nothing of the day is printed or recorded
+                    Err(err @ InputError::Unsolved { .. }) => {
+                        eprintln!("Day {number}: {err}");
+                        status = ExitCode::FAILURE;
+                        continue;
+                    }
+                };
+
+                for part in &result.parts {
+                    let key = AnswerKey {
+                        day: number,
+                        part: part.part,
+                        input: input_hash,
+                    };
+                    if record {
+                        answers.insert(key, part.answer.clone());
+                    }
+                    if verify {
+                        checks.push(Check {
+                            key,
+                            expected: answers.get(key).map(str::to_owned),
+                            actual: part.answer.clone(),
+                        });
+                    }
+                }
+                if !verify {
+                    println!("{}", output.render(&result));
+                }
+            }
+
+            if record {
+                if let Err(err) = answers.save(&answers_path) {
+                    eprintln!("Unable to write {}: {err}", answers_path.display());
+                    return ExitCode::FAILURE;
+                }
+            }
+            if verify {
+                println!("{}", answers::verification_report(&checks));
+                if checks
+                    .iter()
+                    .any(|check| check.expected.is_some() && !check.matches())
+                {
+                    status = ExitCode::FAILURE;
+                }
+            }
+            status
+        }
+        Command::Bench {
+            days,
+            input,
+            iterations,
+            output,
+            threshold,
+        } => {
+            if input.input.is_some() && days.start() != days.end() {
+                eprintln!("--input can only be used when running a single day");
+                return ExitCode::FAILURE;
+            }
+
+            let mut results = match BenchResults::load(&output) {
+                Ok(results) => results,
+                Err(err) => {
+                    eprintln!("Unable to read {}: {err}", output.display());
+                    return ExitCode::FAILURE;
+                }
+            };
+            let previous = results.clone();
+
+            let mut status = ExitCode::SUCCESS;
+            let mut current = BenchResults::default();
+            for number in days {
+                let day = days::get(number).unwrap();
+                let Some(contents) = read_input(number, &input) else {
+                    status = ExitCode::FAILURE;
+                    continue;
+                };
+                match (day.bench)(&contents, iterations) {
+                    Ok(bench) => {
+                        current.days.insert(number, (&bench).into());
+                    }
+                    Err(err) => {
+                        report_parse_error(number, &input, &contents, err);
+                        status = ExitCode::FAILURE;
+                    }
+                }
+            }
+
+            let (report, regressions) = bench::compare(&current, &previous, threshold);
+            println!("{report}");
+            if regressions > 0 {
+                status = ExitCode::FAILURE;
+            }
+
+            // Days that were not benchmarked keep their earlier timings
+            results.days.extend(current.days);
+            if let Err(err) = results.save(&output) {
+                eprintln!("Unable to write {}: {err}", output.display());
+                return ExitCode::FAILURE;
+            }
+            status
+        }
+        Command::Fetch {
+            days,
+            year,
+            base_url,
+        } => {
+            let fetcher = Fetcher {
+                base_url,
+                year,
+                session: env::var("AOC_SESSION")
+                    .ok()
+                    .filter(|session| !session.is_empty()),
+            };
+
+            let mut status = ExitCode::SUCCESS;
+            for number in days {
+                let path = PathBuf::from(format!("d{number}/inputs/input.txt"));
+                match fetcher.fetch(number, &path) {
+                    Ok(Fetched::Downloaded) => println!("Day {number}: saved {}", path.display()),
+                    Ok(Fetched::Cached) => {
+                        println!("Day {number}: {} already exists", path.display())
+                    }
+                    Err(err @ FetchError::MissingSession(_)) => {
+                        eprintln!("{err}");
+                        return ExitCode::FAILURE;
+                    }
+                    Err(err) => {
+                        eprintln!("Day {number}: {err}");
+                        status = ExitCode::FAILURE;
+                    }
+                }
+            }
+            status
+        }
+    }
+}
@@ -0,0 +1,324 @@
+pub mod metric;
+
+use std::{cmp::Ordering, io::BufRead};
+
+use aoc_core::{
+    parse::{self, Cursor},
+    InputError, ParseError, Solution, SolveError,
+};
+use metric::Metric;
+
+/// One location list both in input order and sorted. Metrics that pair up
+/// the lists line by line use the former, the others the latter.
+#[derive(Debug, Clone, Copy)]
+pub struct Column<'a> {
+    pub input_order: &'a [usize],
+    pub sorted: &'a [usize],
+}
+
+/// Any number (at least two) of location lists, one per column. The puzzle
+/// compares the first two.
+#[derive(Debug, Clone)]
+pub struct LocationLists {
+    pub columns: Vec<Vec<usize>>,
+}
+
+impl LocationLists {
+    fn new() -> LocationLists {
+        LocationLists {
+            columns: Vec::new(),
+        }
+    }
+
+    /// Columns may be separated by any whitespace. The first line decides
+    /// how many columns there are.
+    fn push_line(&mut self, number: usize, line: &str) -> Result<(), ParseError> {
+        let first_line = self.columns.is_empty();
+        let mut cursor = Cursor::new(number, line);
+        cursor.skip_whitespace();
+
+        let mut column = 0;
+        while !cursor.is_at_end() {
+            if !first_line && column == self.columns.len() {
+                return Err(cursor.error("end of line"));
+            }
+            let value = cursor.number()?;
+            if !cursor.skip_whitespace() && !cursor.is_at_end() {
+                return Err(cursor.error("whitespace"));
+            }
+
+            if first_line {
+                self.columns.push(Vec::new());
+            }
+            self.columns[column].push(value);
+            column += 1;
+        }
+
+        if first_line && column < 2 {
+            return Err(cursor.error("a second location ID"));
+        }
+        if column < self.columns.len() {
+            return Err(cursor.error(format!("{} location IDs per line", self.columns.len())));
+        }
+        Ok(())
+    }
+
+    /// Sorts every column in place.
+    pub fn sort(&mut self) {
+        for column in &mut self.columns {
+            column.sort_unstable();
+        }
+    }
+
+    fn finish(self, lines: usize) -> Result<LocationLists, ParseError> {
+        if self.columns.is_empty() {
+            return Err(ParseError::end_of_input(lines, "a location ID"));
+        }
+        Ok(self)
+    }
+}
+
+pub fn parse_lists(contents: &str) -> Result<LocationLists, ParseError> {
+    let mut lists = LocationLists::new();
+    let mut lines = 0;
+    for (number, line) in parse::lines(contents) {
+        lists.push_line(number, line)?;
+        lines = number;
+    }
+    lists.finish(lines)
+}
+
+/// Reads the lists line by line in a single pass, so apart from one line
+/// buffer only the columns are kept in memory.
+pub fn read_lists(mut reader: impl BufRead) -> Result<LocationLists, InputError> {
+    let mut lists = LocationLists::new();
+    let mut line = String::new();
+    let mut number = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        number += 1;
+        lists.push_line(number, line.trim_end_matches(['\n', '\r']))?;
+    }
+    Ok(lists.finish(number)?)
+}
+
+/// The lists prepared for [`Metric`]s: a copy in input order next to the
+/// sorted columns.
+#[derive(Debug)]
+pub struct MetricLists {
+    input_order: LocationLists,
+    sorted: LocationLists,
+}
+
+impl MetricLists {
+    pub fn len(&self) -> usize {
+        self.sorted.columns.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sorted.columns.is_empty()
+    }
+
+    pub fn column(&self, index: usize) -> Column<'_> {
+        Column {
+            input_order: &self.input_order.columns[index],
+            sorted: &self.sorted.columns[index],
+        }
+    }
+}
+
+impl From<LocationLists> for MetricLists {
+    fn from(input_order: LocationLists) -> MetricLists {
+        let mut sorted = input_order.clone();
+        sorted.sort();
+        MetricLists {
+            input_order,
+            sorted,
+        }
+    }
+}
+
+/// Distance between the two lists paired up in sorted order, both slices
+/// have to be sorted.
+pub fn get_difference(left: &[usize], right: &[usize]) -> usize {
+    left.iter()
+        .zip(right)
+        .map(|(left, right)| left.abs_diff(*right))
+        .sum()
+}
+
+/// Number of values equal to `values[start]` from `start` on.
+fn run_length(values: &[usize], start: usize) -> usize {
+    values[start..]
+        .iter()
+        .take_while(|&&value| value == values[start])
+        .count()
+}
+
+/// Merges two sorted lists, calling `f` with every distinct value and how
+/// often it appears in `left` and in `right` (one of them may be 0).
+pub fn merge_counts(left: &[usize], right: &[usize], mut f: impl FnMut(usize, usize, usize)) {
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() || j < right.len() {
+        let ordering = match (left.get(i), right.get(j)) {
+            (Some(left), Some(right)) => left.cmp(right),
+            (Some(_), None) => Ordering::Less,
+            _ => Ordering::Greater,
+        };
+        let (value, left_count, right_count) = match ordering {
+            Ordering::Less => (left[i], run_length(left, i), 0),
+            Ordering::Greater => (right[j], 0, run_length(right, j)),
+            Ordering::Equal => (left[i], run_length(left, i), run_length(right, j)),
+        };
+        f(value, left_count, right_count);
+        i += left_count;
+        j += right_count;
+    }
+}
+
+/// Every left value times how often it appears on the right, found by
+/// merging the two sorted lists instead of counting into a map.
+pub fn get_simmilarity(left: &[usize], right: &[usize]) -> usize {
+    let mut simmilarity = 0;
+    merge_counts(left, right, |value, left_count, right_count| {
+        simmilarity += value * left_count * right_count;
+    });
+    simmilarity
+}
+
+/// `metric` for every ordered pair of columns, `matrix[a][b]` compares
+/// column `a` (as the left list) with column `b`.
+pub fn pairwise(lists: &MetricLists, metric: &dyn Metric) -> Vec<Vec<f64>> {
+    (0..lists.len())
+        .map(|left| {
+            (0..lists.len())
+                .map(|right| metric.compare(lists.column(left), lists.column(right)))
+                .collect()
+        })
+        .collect()
+}
+
+/// Renders a matrix from [`pairwise`] below the metric's name, with the
+/// (1-based) column numbers as row and column labels.
+pub fn render_matrix(metric: &dyn Metric, matrix: &[Vec<f64>]) -> String {
+    let matrix: Vec<Vec<String>> = matrix
+        .iter()
+        .map(|row| row.iter().map(|&value| metric.format(value)).collect())
+        .collect();
+    let width = matrix
+        .iter()
+        .flatten()
+        .map(String::len)
+        .chain([matrix.len().to_string().len()])
+        .max()
+        .unwrap_or(1);
+
+    let mut rendered = format!("{}\n{:width$}", metric.name(), "");
+    for column in 1..=matrix.len() {
+        rendered += &format!(" {column:>width$}");
+    }
+    for (row, values) in matrix.iter().enumerate() {
+        rendered += &format!("\n{:>width$}", row + 1);
+        for value in values {
+            rendered += &format!(" {value:>width$}");
+        }
+    }
+    rendered
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    /// The lists with every column sorted in place, as both parts pair
+    /// them up in sorted order.
+    type Input = LocationLists;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lists = parse_lists(input)?;
+        lists.sort();
+        Ok(lists)
+    }
+
+    fn parse_reader(reader: &mut dyn BufRead) -> Result<Self::Input, InputError> {
+        let mut lists = read_lists(reader)?;
+        lists.sort();
+        Ok(lists)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        get_difference(&input.columns[0], &input.columns[1])
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(get_simmilarity(&input.columns[0], &input.columns[1]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part1(&input), 11);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part2(&input), Ok(31));
+    }
+
+    #[test]
+    fn streaming_matches_parse() {
+        let streamed = read_lists(EXAMPLE.as_bytes()).unwrap();
+        let parsed = parse_lists(EXAMPLE).unwrap();
+        assert_eq!(streamed.columns, parsed.columns);
+    }
+
+    #[test]
+    fn columns_separated_by_any_whitespace() {
+        let lists = parse_lists("3\t4 \t 1\n 4  3\t2\n").unwrap();
+        assert_eq!(lists.columns, [[3, 4], [4, 3], [1, 2]]);
+    }
+
+    #[test]
+    fn lines_need_as_many_columns_as_the_first() {
+        let err = parse_lists("3 4 1\n4 3\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+        let err = parse_lists("3 4\n4 3 1\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+    }
+
+    #[test]
+    fn solving_sorts_in_place() {
+        let input = Day1::parse("3 4\n1 3\n2 5\n").unwrap();
+        assert_eq!(input.columns, [[1, 2, 3], [3, 4, 5]]);
+
+        // Metrics get the input order next to the sorted copy
+        let lists = MetricLists::from(parse_lists("3 4\n1 3\n2 5\n").unwrap());
+        assert_eq!(lists.column(1).input_order, [4, 3, 5]);
+        assert_eq!(lists.column(1).sorted, [3, 4, 5]);
+    }
+
+    #[test]
+    fn pairwise_distances() {
+        let lists = MetricLists::from(parse_lists("1 2 4\n3 2 4\n").unwrap());
+        let matrix = pairwise(&lists, &metric::SortedL1);
+        assert_eq!(matrix, [[0.0, 2.0, 4.0], [2.0, 0.0, 4.0], [4.0, 4.0, 0.0]]);
+        assert_eq!(
+            render_matrix(&metric::SortedL1, &matrix),
+            "Sorted distance\n  1 2 3\n1 0 2 4\n2 2 0 4\n3 4 4 0"
+        );
+    }
+}
@@ -1,59 +1,54 @@
-use std::{collections::HashMap, fs};
+use std::process::ExitCode;
 
-fn get_difference(input_file: &str) -> i32 {
-    let line_count = fs::read_to_string(input_file).expect("Unable to read file").lines().count();
-    let contents = fs::read_to_string(input_file).expect("Unable to read file");
+use aoc_core::cli::{self, DayArgs};
+use clap::Parser;
+use d1::{metric::MetricKind, pairwise, read_lists, render_matrix, Day1, MetricLists};
 
-    let mut left_nums: Vec<i32> = vec![0; line_count];
-    let mut right_nums: Vec<i32> = vec![0; line_count];
+#[derive(Parser)]
+#[command(about = "Solves day 1 of Advent of Code 2024")]
+struct Cli {
+    #[command(flatten)]
+    day: DayArgs,
 
-    let mut sum: i32 = 0;
-    for (i, line) in contents.lines().enumerate() {
-        let split_line: Vec<&str> = line.split("   ").collect();
-        let left_num: i32 = split_line[0].parse().unwrap();
-        let right_num: i32 = split_line[1].parse().unwrap();
-        left_nums[i] = left_num;
-        right_nums[i] = right_num;
-    }
-
-    left_nums.sort();
-    right_nums.sort();
-
-    for i in 0..line_count {
-        sum += (left_nums[i] - right_nums[i]).abs();
-    }
+    /// Compare every pair of columns instead of only the first two
+    #[arg(long)]
+    matrix: bool,
 
-    sum
+    /// Compare the lists with this metric, may be repeated (default: sorted-l1 and similarity)
+    #[arg(long, value_enum, value_name = "METRIC")]
+    metric: Vec<MetricKind>,
 }
 
-fn get_simmilarity(input_file: &str) -> usize {
-    let line_count = fs::read_to_string(input_file).expect("Unable to read file").lines().count();
-    let contents = fs::read_to_string(input_file).expect("Unable to read file");
-
-    let mut left_nums: Vec<usize> = vec![0; line_count];
-    let mut right_nums: HashMap<usize, usize> = HashMap::new();
-
-    let mut sum: usize = 0;
-    for (i, line) in contents.lines().enumerate() {
-        let split_line: Vec<&str> = line.split("   ").collect();
-        let left_num: usize = split_line[0].parse().unwrap();
-        let right_num: usize = split_line[1].parse().unwrap();
-        left_nums[i] = left_num;
-        let num = right_nums.entry(right_num).or_insert(0);
-        *num += 1;
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if !cli.matrix && cli.metric.is_empty() {
+        return cli::run::<Day1>(&cli.day);
     }
 
-    for i in 0..line_count {
-        sum += left_nums[i] * right_nums.get(&(left_nums[i] as usize)).unwrap_or(&0);
-    }
-
-    sum
-}
-
-fn main() {
-    const INPUT_FILE: &str = "inputs/input.txt";
-    let difference = get_difference(INPUT_FILE);
-    let simmilarity = get_simmilarity(INPUT_FILE);
-
-    println!("Difference: {difference}\nSimmilarity: {simmilarity}");
+    // The metrics get the lists in input order next to a sorted copy
+    let Some(lists) = cli::load_with(&cli.day.input, |reader| read_lists(reader)) else {
+        return ExitCode::FAILURE;
+    };
+    let lists = MetricLists::from(lists);
+    let metrics = if cli.metric.is_empty() {
+        vec![MetricKind::SortedL1, MetricKind::Similarity]
+    } else {
+        cli.metric
+    };
+
+    let reports: Vec<String> = metrics
+        .into_iter()
+        .map(MetricKind::metric)
+        .map(|metric| {
+            if cli.matrix {
+                render_matrix(metric, &pairwise(&lists, metric))
+            } else {
+                let value = metric.compare(lists.column(0), lists.column(1));
+                format!("{}: {}", metric.name(), metric.format(value))
+            }
+        })
+        .collect();
+    let separator = if cli.matrix { "\n\n" } else { "\n" };
+    println!("{}", reports.join(separator));
+    ExitCode::SUCCESS
 }
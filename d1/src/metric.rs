@@ -0,0 +1,224 @@
+use clap::ValueEnum;
+
+use crate::{get_difference, get_simmilarity, merge_counts, Column};
+
+/// A way of comparing two location lists.
+pub trait Metric {
+    /// Title used when printing the results.
+    fn name(&self) -> &'static str;
+
+    fn compare(&self, left: Column<'_>, right: Column<'_>) -> f64;
+
+    /// Metrics that only produce whole numbers print them without decimals.
+    fn format(&self, value: f64) -> String {
+        format!("{value:.4}")
+    }
+}
+
+/// The part 1 distance between the lists paired up in sorted order.
+pub struct SortedL1;
+
+impl Metric for SortedL1 {
+    fn name(&self) -> &'static str {
+        "Sorted distance"
+    }
+
+    fn compare(&self, left: Column<'_>, right: Column<'_>) -> f64 {
+        get_difference(left.sorted, right.sorted) as f64
+    }
+
+    fn format(&self, value: f64) -> String {
+        value.to_string()
+    }
+}
+
+/// The part 2 similarity score.
+pub struct SimilarityScore;
+
+impl Metric for SimilarityScore {
+    fn name(&self) -> &'static str {
+        "Similarity score"
+    }
+
+    fn compare(&self, left: Column<'_>, right: Column<'_>) -> f64 {
+        get_simmilarity(left.sorted, right.sorted) as f64
+    }
+
+    fn format(&self, value: f64) -> String {
+        value.to_string()
+    }
+}
+
+/// Share of the distinct location IDs that appear in both lists.
+pub struct Jaccard;
+
+impl Metric for Jaccard {
+    fn name(&self) -> &'static str {
+        "Jaccard index"
+    }
+
+    fn compare(&self, left: Column<'_>, right: Column<'_>) -> f64 {
+        let (mut shared, mut all) = (0, 0);
+        merge_counts(left.sorted, right.sorted, |_, left_count, right_count| {
+            all += 1;
+            if left_count > 0 && right_count > 0 {
+                shared += 1;
+            }
+        });
+        if all == 0 {
+            return 1.0;
+        }
+        shared as f64 / all as f64
+    }
+}
+
+/// How many IDs the lists have in common counting duplicates, as a share of
+/// the longer list.
+pub struct HistogramIntersection;
+
+impl Metric for HistogramIntersection {
+    fn name(&self) -> &'static str {
+        "Histogram intersection"
+    }
+
+    fn compare(&self, left: Column<'_>, right: Column<'_>) -> f64 {
+        let mut common = 0;
+        merge_counts(left.sorted, right.sorted, |_, left_count, right_count| {
+            common += left_count.min(right_count);
+        });
+        let longer = left.sorted.len().max(right.sorted.len());
+        if longer == 0 {
+            return 1.0;
+        }
+        common as f64 / longer as f64
+    }
+}
+
+/// Rank correlation of the lists paired up line by line as they appear in
+/// the input. A list holding a single distinct ID doesn't vary, so nothing
+/// correlates with it and the correlation is 0.
+pub struct Spearman;
+
+impl Metric for Spearman {
+    fn name(&self) -> &'static str {
+        "Spearman rank correlation"
+    }
+
+    fn compare(&self, left: Column<'_>, right: Column<'_>) -> f64 {
+        pearson(&ranks(left.input_order), &ranks(right.input_order))
+    }
+}
+
+/// 1-based rank of every value, equal values share the mean of their ranks.
+fn ranks(values: &[usize]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_unstable_by_key(|&i| values[i]);
+
+    let mut ranks = vec![0.0; values.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let value = values[order[start]];
+        let end = start
+            + order[start..]
+                .iter()
+                .take_while(|&&i| values[i] == value)
+                .count();
+        let rank = (start + end + 1) as f64 / 2.0;
+        for &i in &order[start..end] {
+            ranks[i] = rank;
+        }
+        start = end;
+    }
+    ranks
+}
+
+/// Pearson correlation, 0 if either side has no variance.
+fn pearson(x: &[f64], y: &[f64]) -> f64 {
+    let n = x.len().min(y.len()) as f64;
+    if n == 0.0 {
+        return 0.0;
+    }
+    let mean_x = x.iter().sum::<f64>() / n;
+    let mean_y = y.iter().sum::<f64>() / n;
+
+    let (mut covariance, mut variance_x, mut variance_y) = (0.0, 0.0, 0.0);
+    for (x, y) in x.iter().zip(y) {
+        let (dx, dy) = (x - mean_x, y - mean_y);
+        covariance += dx * dy;
+        variance_x += dx * dx;
+        variance_y += dy * dy;
+    }
+    if variance_x == 0.0 || variance_y == 0.0 {
+        return 0.0;
+    }
+    covariance / (variance_x * variance_y).sqrt()
+}
+
+/// The metrics that can be picked on the command line.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricKind {
+    SortedL1,
+    Similarity,
+    Jaccard,
+    HistogramIntersection,
+    Spearman,
+}
+
+impl MetricKind {
+    pub fn metric(self) -> &'static dyn Metric {
+        match self {
+            MetricKind::SortedL1 => &SortedL1,
+            MetricKind::Similarity => &SimilarityScore,
+            MetricKind::Jaccard => &Jaccard,
+            MetricKind::HistogramIntersection => &HistogramIntersection,
+            MetricKind::Spearman => &Spearman,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_lists, MetricLists};
+
+    const EXAMPLE: &str = include_str!("../inputs/example.txt");
+
+    fn compare(metric: MetricKind) -> f64 {
+        let lists = MetricLists::from(parse_lists(EXAMPLE).unwrap());
+        metric.metric().compare(lists.column(0), lists.column(1))
+    }
+
+    #[test]
+    fn puzzle_metrics_match_the_parts() {
+        assert_eq!(compare(MetricKind::SortedL1), 11.0);
+        assert_eq!(compare(MetricKind::Similarity), 31.0);
+    }
+
+    #[test]
+    fn set_and_histogram_overlap() {
+        // {1, 2, 3, 4} and {3, 4, 5, 9} share two of six IDs
+        assert_eq!(compare(MetricKind::Jaccard), 2.0 / 6.0);
+        // Three 3s and one 4 appear in both lists of six
+        assert_eq!(compare(MetricKind::HistogramIntersection), 4.0 / 6.0);
+    }
+
+    #[test]
+    fn spearman_averages_tied_ranks() {
+        assert_eq!(ranks(&[3, 4, 2, 1, 3, 3]), [4.0, 6.0, 2.0, 1.0, 4.0, 4.0]);
+        assert!((compare(MetricKind::Spearman) + 3.0 / 31.0).abs() < 1e-12);
+
+        let lists = MetricLists::from(parse_lists("1 10\n2 20\n3 30\n").unwrap());
+        let correlation = Spearman.compare(lists.column(0), lists.column(1));
+        assert!((correlation - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn spearman_without_variance_is_zero() {
+        let lists = MetricLists::from(parse_lists("5 1\n5 2\n5 3\n").unwrap());
+        assert_eq!(Spearman.compare(lists.column(0), lists.column(1)), 0.0);
+        assert_eq!(Spearman.compare(lists.column(0), lists.column(0)), 0.0);
+
+        let lists = MetricLists::from(parse_lists("1 2\n").unwrap());
+        assert_eq!(crate::pairwise(&lists, &Spearman), [[0.0, 0.0], [0.0, 0.0]]);
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_core::{ParseError, Solution, SolveError};
+use aoc_grid::{Grid, Point};
+
+// pathfinding::directed::bfs could spare me the
+// implementation, I want to learn a bit though,
+// which is why I'll implement it myself
+//
+// Edit: This was the right descision as the second part
+// requires you to modify BFS to count the paths (we know
+// that no cycles can exist in our graph)
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct Node {
+    position: Point<usize>,
+    final_elevation: bool,
+}
+
+pub struct TopographicalMap {
+    /// Valid next moves from every position
+    pub map: Grid<Vec<Node>>,
+    pub trailheads: Vec<Point<usize>>,
+}
+
+pub fn get_trail_scores(topographical_map: &Grid<Vec<Node>>, trailheads: &[Point<usize>]) -> usize {
+    let mut trail_count = 0;
+    for &trailhead in trailheads {
+        let mut reachable_goals: HashSet<Node> = HashSet::new();
+        let mut queue: VecDeque<Node> = VecDeque::new();
+        let mut explored = Grid::new(topographical_map.width(), topographical_map.height(), false);
+        explored[trailhead] = true;
+        queue.push_back(Node {
+            position: trailhead,
+            final_elevation: false,
+        });
+
+        while let Some(v) = queue.pop_front() {
+            if v.final_elevation {
+                reachable_goals.insert(v.clone());
+            }
+            for w in &topographical_map[v.position] {
+                if explored[w.position] {
+                    continue;
+                }
+
+                explored[w.position] = true;
+                queue.push_back(w.clone());
+            }
+        }
+        trail_count += reachable_goals.len();
+    }
+
+    trail_count
+}
+
+pub fn get_trail_ratings(
+    topographical_map: &Grid<Vec<Node>>,
+    trailheads: &[Point<usize>],
+) -> usize {
+    let mut trail_count = 0;
+    for &trailhead in trailheads {
+        let mut queue: VecDeque<Node> = VecDeque::new();
+        queue.push_back(Node {
+            position: trailhead,
+            final_elevation: false,
+        });
+
+        while let Some(v) = queue.pop_front() {
+            if v.final_elevation {
+                trail_count += 1;
+            }
+            for w in &topographical_map[v.position] {
+                queue.push_back(w.clone());
+            }
+        }
+    }
+
+    trail_count
+}
+
+pub fn parse_map(contents: &str) -> Result<TopographicalMap, ParseError> {
+    // Find the number of trail paths (-> paths starting at a trailhead that lead to an elevation of 9)
+    let elevations = Grid::parse(contents, "an elevation (0-9)", |elevation| {
+        elevation.to_digit(10).map(|elevation| elevation as u8)
+    })?;
+
+    let trailheads: Vec<Point<usize>> = elevations
+        .iter()
+        .filter(|(_, &elevation)| elevation == 0)
+        .map(|(position, _)| position)
+        .collect();
+
+    // Iterate over the input and generate our graph
+    let mut topographical_map = Grid::new(elevations.width(), elevations.height(), Vec::new());
+    for (position, &elevation) in elevations.iter() {
+        for neighbor_pos in elevations.neighbors4(position) {
+            let neighboring_elevation = elevations[neighbor_pos];
+
+            if neighboring_elevation == elevation + 1 {
+                // Valid next move found
+                topographical_map[position].push(Node {
+                    position: neighbor_pos,
+                    final_elevation: neighboring_elevation == 9,
+                });
+            }
+        }
+    }
+
+    Ok(TopographicalMap {
+        map: topographical_map,
+        trailheads,
+    })
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = TopographicalMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        get_trail_scores(&input.map, &input.trailheads)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(get_trail_ratings(&input.map, &input.trailheads))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part1(&input), 36);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part2(&input), Ok(81));
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use aoc_core::{
+    parse::{self, Cursor, Found},
+    ParseError, Solution, SolveError,
+};
+
+fn digit_even(number: u64) -> bool {
+    match u64::checked_ilog10(number) {
+        Some(num) => (num + 1) % 2 == 0,
+        None => false,
+    }
+}
+
+fn get_split_engravings(number: u64) -> (u64, u64) {
+    let digit_count = (number as f64).log10() as u32 + 1;
+    let half_len = digit_count / 2;
+    let divisor = 10u64.pow(half_len);
+
+    let right = number % divisor;
+    let left = number / divisor;
+
+    (left, right)
+}
+
+pub fn step_stone(stone: u64, num_steps: usize, cache: &mut HashMap<(u64, usize), u64>) -> u64 {
+    if let Some(&cached) = cache.get(&(stone, num_steps)) {
+        return cached;
+    }
+
+    let count = if num_steps == 1 {
+        if stone == 0 {
+            1
+        } else if digit_even(stone) {
+            2
+        } else {
+            1
+        }
+    } else if stone == 0 {
+        step_stone(1, num_steps - 1, cache)
+    } else if digit_even(stone) {
+        let (left, right) = get_split_engravings(stone);
+        step_stone(left, num_steps - 1, cache) + step_stone(right, num_steps - 1, cache)
+    } else {
+        step_stone(stone * 2024, num_steps - 1, cache)
+    };
+
+    cache.insert((stone, num_steps), count);
+    count
+}
+
+pub fn count_stones(stones: &[u64], num_steps: usize) -> u64 {
+    let mut stone_cache = HashMap::new();
+    stones
+        .iter()
+        .map(|&stone| step_stone(stone, num_steps, &mut stone_cache))
+        .sum()
+}
+
+/// A single line of stones separated by spaces.
+pub fn parse_stones(contents: &str) -> Result<Vec<u64>, ParseError> {
+    let mut lines = parse::lines(contents.trim_end());
+    let Some((number, line)) = lines.next() else {
+        return Err(ParseError::new(1, 1, "a number", Found::EndOfInput));
+    };
+
+    let mut cursor = Cursor::new(number, line);
+    let mut stones = vec![cursor.number()?];
+    while !cursor.is_at_end() {
+        cursor.expect(" ")?;
+        stones.push(cursor.number()?);
+    }
+
+    if let Some((number, line)) = lines.next() {
+        return Err(Cursor::new(number, line).error("end of input"));
+    }
+    Ok(stones)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<u64>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_stones(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        count_stones(input, 25)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(count_stones(input, 75))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part1(&input), 55312);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part2(&input), Ok(65601038650482));
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::VecDeque;
+
+use aoc_core::{ParseError, Solution, SolveError};
+use aoc_grid::{Direction, Grid, Point};
+
+/// Returns the fencing price by perimeter and the discounted price by number
+/// of sides.
+pub fn get_fencing_prices(garden: &Grid<char>) -> (usize, usize) {
+    let mut explored = Grid::new(garden.width(), garden.height(), false);
+    let mut fencing_region_price = 0;
+    let mut new_fencing_region_price = 0;
+
+    for start in garden.positions() {
+        if explored[start] {
+            continue;
+        }
+
+        // Explore region
+        let mut queue: VecDeque<Point<usize>> = VecDeque::new();
+        let current_region = garden[start];
+        let mut current_area = 0;
+        let mut current_perimiter = 0;
+        let mut current_sides = 0;
+        queue.push_back(start);
+
+        // Keep track of borders and only add to "sides" if new border doesn't extend any previous borders
+        let mut explored_borders: Vec<(Direction, Point<isize>)> = Vec::new();
+
+        while let Some(position) = queue.pop_front() {
+            if explored[position] {
+                continue;
+            }
+            current_area += 1;
+            explored[position] = true;
+            current_perimiter += 4;
+            for wall_direction in Direction::ALL {
+                let neighbor_pos = garden
+                    .step(position, wall_direction)
+                    .filter(|&neighbor_pos| garden[neighbor_pos] == current_region);
+
+                if let Some(neighbor_pos) = neighbor_pos {
+                    current_perimiter -= 1;
+
+                    if !explored[neighbor_pos] {
+                        queue.push_back(neighbor_pos);
+                    }
+                    continue;
+                }
+
+                // There's a wall between us and the neighbor, which might lie
+                // outside of the garden
+                let wall = position.signed() + wall_direction.delta();
+
+                if explored_borders
+                    .iter()
+                    .any(|border| border.0 == wall_direction && border.1 == wall)
+                {
+                    continue;
+                }
+
+                // We know there's a wall, so check for adjacent walls we might've
+                // already explored
+                let mut adjacent_walls = 0;
+                for orth_wall_direction in wall_direction.orthogonal() {
+                    let adjacent_wall = wall + orth_wall_direction.delta();
+                    adjacent_walls += explored_borders
+                        .iter()
+                        .filter(|border| border.0 == wall_direction && border.1 == adjacent_wall)
+                        .count()
+                }
+
+                // If we didn't find an adjacent wall, add one to our sides
+                current_sides = current_sides + 1 - adjacent_walls;
+                // Add our wall to explored_borders
+                explored_borders.push((wall_direction, wall));
+            }
+        }
+
+        fencing_region_price += current_area * current_perimiter;
+        new_fencing_region_price += current_area * current_sides;
+    }
+
+    (fencing_region_price, new_fencing_region_price)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, "a plant", Some)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        get_fencing_prices(input).0
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(get_fencing_prices(input).1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part1(&input), 1930);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part2(&input), Ok(1206));
+    }
+}
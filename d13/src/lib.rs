@@ -0,0 +1,126 @@
+use aoc_core::{
+    parse::{self, Cursor},
+    ParseError, Solution, SolveError,
+};
+
+pub struct Machine {
+    pub ax: f64,
+    pub ay: f64,
+    pub bx: f64,
+    pub by: f64,
+    pub rx: f64,
+    pub ry: f64,
+}
+
+/// Parses a `<prefix><x><separator><y>` line such as `Prize: X=8400, Y=5400`.
+fn parse_coordinates(
+    line: Option<(usize, &str)>,
+    line_count: usize,
+    prefix: &str,
+    separator: &str,
+) -> Result<(f64, f64), ParseError> {
+    let Some((number, line)) = line else {
+        return Err(ParseError::end_of_input(line_count, format!("`{prefix}`")));
+    };
+
+    let mut cursor = Cursor::new(number, line);
+    cursor.expect(prefix)?;
+    let x: u32 = cursor.number()?;
+    cursor.expect(separator)?;
+    let y: u32 = cursor.number()?;
+    cursor.end()?;
+    Ok((x.into(), y.into()))
+}
+
+/// Machines are blocks of three lines (button A, button B and the prize),
+/// separated by blank lines.
+pub fn parse_machines(contents: &str) -> Result<Vec<Machine>, ParseError> {
+    let line_count = contents.lines().count();
+    let mut lines = parse::lines(contents).filter(|(_, line)| !line.is_empty());
+
+    let mut machines = Vec::new();
+    while let Some(line) = lines.next() {
+        let (ax, ay) = parse_coordinates(Some(line), line_count, "Button A: X+", ", Y+")?;
+        let (bx, by) = parse_coordinates(lines.next(), line_count, "Button B: X+", ", Y+")?;
+        let (rx, ry) = parse_coordinates(lines.next(), line_count, "Prize: X=", ", Y=")?;
+        machines.push(Machine {
+            ax,
+            ay,
+            bx,
+            by,
+            rx,
+            ry,
+        });
+    }
+    Ok(machines)
+}
+
+/// Tokens needed to win every winnable prize, with `prize_offset` added to
+/// both prize coordinates.
+pub fn tokens_spent(machines: &[Machine], prize_offset: f64) -> f64 {
+    let mut tokens_spent = 0.0;
+    for &Machine {
+        ax,
+        ay,
+        bx,
+        by,
+        rx,
+        ry,
+    } in machines
+    {
+        let rx = rx + prize_offset;
+        let ry = ry + prize_offset;
+
+        // How many times we need to press a / b
+        let a = (-bx * ry + by * rx) / (ax * by - bx * ay);
+        let b = (ax * ry - ay * rx) / (ax * by - bx * ay);
+
+        if a >= 0.0 && a.fract() == 0.0 && b >= 0.0 && b.fract() == 0.0 {
+            // Cost of A: 3
+            // Cost of B: 1
+            tokens_spent += a * 3.0 + b;
+        }
+    }
+    tokens_spent
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Machine>;
+    type Part1 = f64;
+    type Part2 = f64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_machines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        tokens_spent(input, 0.0)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(tokens_spent(input, 10000000000000f64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part1(&input), 480.0);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part2(&input), Ok(875318608908.0));
+    }
+}
@@ -0,0 +1,276 @@
+use std::{fmt, sync::OnceLock};
+
+use aoc_core::{
+    parse::{self, Cursor, Found},
+    ParseError, Solution, SolveError,
+};
+use aoc_grid::{Grid, Point};
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    pub position: Point<i32>,
+    pub velocity: Point<i32>,
+}
+
+#[derive(Debug)]
+pub struct Bathroom {
+    pub robots: Vec<Robot>,
+    pub width: usize,
+    pub height: usize,
+    /// What [`find_tree`] found, kept so part 2 and the visualization only
+    /// search once.
+    tree: OnceLock<Option<(usize, Vec<Robot>)>>,
+}
+
+impl Bathroom {
+    pub fn new(robots: Vec<Robot>, width: usize, height: usize) -> Bathroom {
+        Bathroom {
+            robots,
+            width,
+            height,
+            tree: OnceLock::new(),
+        }
+    }
+
+    /// The first christmas tree, see [`find_tree`].
+    pub fn tree(&self) -> Option<&(usize, Vec<Robot>)> {
+        self.tree.get_or_init(|| find_tree(self)).as_ref()
+    }
+}
+
+/// Size of the bathroom of the real inputs, the example's is 11 x 7.
+pub const BATHROOM_SIZE: (usize, usize) = (101, 103);
+
+#[derive(Debug, PartialEq)]
+pub enum TreeSearch {
+    Found(usize),
+    NoTree,
+}
+
+impl fmt::Display for TreeSearch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TreeSearch::Found(seconds) => write!(f, "{seconds}"),
+            TreeSearch::NoTree => write!(f, "No Tree"),
+        }
+    }
+}
+
+impl Robot {
+    pub fn apply_velocity(&mut self, seconds: usize, width: usize, height: usize) {
+        let moved = self.position + self.velocity * seconds as i32;
+        self.position = Point::new(
+            moved.x.rem_euclid(width as i32),
+            moved.y.rem_euclid(height as i32),
+        )
+    }
+}
+
+fn get_quadrant(final_position: &Point<i32>, width: usize, height: usize) -> Option<usize> {
+    if final_position.x < (width / 2) as i32 {
+        if final_position.y < (height / 2) as i32 {
+            Some(0)
+        } else if final_position.y > (height / 2) as i32 {
+            Some(1)
+        } else {
+            None
+        }
+    } else if final_position.x > (width / 2) as i32 {
+        if final_position.y < (height / 2) as i32 {
+            Some(2)
+        } else if final_position.y > (height / 2) as i32 {
+            Some(3)
+        } else {
+            None
+        }
+    } else {
+        None
+    }
+}
+
+fn has_line_of_robots(robots: &[Robot], width: usize, height: usize) -> bool {
+    // Heuristic based on the actual tree
+    // (image contains border so I just check for that)
+    let mut horizontal_lines: Vec<usize> = vec![0; height];
+    let mut vertical_lines: Vec<usize> = vec![0; width];
+    for robot in robots {
+        vertical_lines[robot.position.x as usize] += 1;
+        horizontal_lines[robot.position.y as usize] += 1;
+    }
+
+    let mut robot_lines = 0;
+    for line in horizontal_lines {
+        if line > 31 {
+            robot_lines += 1;
+        }
+    }
+    for line in vertical_lines {
+        if line > 33 {
+            robot_lines += 1;
+        }
+    }
+
+    robot_lines == 4
+}
+
+pub fn get_safety_factor(robots: Vec<Robot>, width: usize, height: usize) -> usize {
+    let mut quadrants: [usize; 4] = [0, 0, 0, 0];
+    for mut robot in robots {
+        // Simulate robot for 100s
+        robot.apply_velocity(100, width, height);
+        // Check which quadrant robot is in and add to count
+        if let Some(quadrant) = get_quadrant(&robot.position, width, height) {
+            quadrants[quadrant] += 1;
+        }
+    }
+
+    quadrants[0] * quadrants[1] * quadrants[2] * quadrants[3]
+}
+
+pub fn display_grid(robots: &[Robot], width: usize, height: usize) -> String {
+    let mut grid = Grid::new(width, height, ' ');
+    for robot in robots {
+        grid[Point::new(robot.position.x as usize, robot.position.y as usize)] = '█';
+    }
+    grid.to_string()
+}
+
+/// Parses a `<x>,<y>` pair. With `bounds` both coordinates have to lie
+/// inside a grid of that size.
+fn parse_point(
+    cursor: &mut Cursor,
+    bounds: Option<(usize, usize)>,
+) -> Result<Point<i32>, ParseError> {
+    let coordinate = |cursor: &mut Cursor, name: &str, limit: Option<usize>| {
+        let column = cursor.column();
+        let value: i32 = cursor.number()?;
+        match limit {
+            Some(limit) if !(0..limit as i32).contains(&value) => Err(ParseError::new(
+                cursor.line(),
+                column,
+                format!("{name} between 0 and {}", limit - 1),
+                Found::Token(value.to_string()),
+            )),
+            _ => Ok(value),
+        }
+    };
+
+    let x = coordinate(cursor, "an x position", bounds.map(|(width, _)| width))?;
+    cursor.expect(",")?;
+    let y = coordinate(cursor, "a y position", bounds.map(|(_, height)| height))?;
+    Ok(Point::new(x, y))
+}
+
+/// One robot per line (`p=0,4 v=3,-3`), every robot has to start inside the
+/// `width` x `height` bathroom.
+pub fn parse_robots(contents: &str, width: usize, height: usize) -> Result<Vec<Robot>, ParseError> {
+    let mut robots = Vec::with_capacity(contents.lines().count());
+    for (number, line) in parse::lines(contents) {
+        let mut cursor = Cursor::new(number, line);
+        cursor.expect("p=")?;
+        let position = parse_point(&mut cursor, Some((width, height)))?;
+        cursor.expect(" v=")?;
+        let velocity = parse_point(&mut cursor, None)?;
+        cursor.end()?;
+
+        robots.push(Robot { position, velocity });
+    }
+    Ok(robots)
+}
+
+/// Robots in a `width` x `height` bathroom, all of them have to start inside.
+pub fn parse_bathroom(contents: &str, width: usize, height: usize) -> Result<Bathroom, ParseError> {
+    let robots = parse_robots(contents, width, height)?;
+    Ok(Bathroom::new(robots, width, height))
+}
+
+/// Steps the robots until they form the christmas tree and returns the
+/// number of seconds that took together with the final robot positions.
+/// After `width * height` seconds every robot is back where it started, so
+/// if there is no tree by then there never will be.
+pub fn find_tree(bathroom: &Bathroom) -> Option<(usize, Vec<Robot>)> {
+    let mut robots = bathroom.robots.clone();
+    for i in 1..=bathroom.width * bathroom.height {
+        for robot in &mut robots {
+            robot.apply_velocity(1, bathroom.width, bathroom.height);
+        }
+
+        // Original solution had rough heuristics to check for the tree
+        // and didn't terminate when a tree was found but let me press
+        // enter until i found the tree
+        if has_line_of_robots(&robots, bathroom.width, bathroom.height) {
+            return Some((i, robots));
+        }
+    }
+    None
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Bathroom;
+    type Part1 = usize;
+    type Part2 = TreeSearch;
+
+    /// The robots in a bathroom of [`BATHROOM_SIZE`], the binary's `--size`
+    /// picks another one.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (width, height) = BATHROOM_SIZE;
+        parse_bathroom(input, width, height)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        get_safety_factor(input.robots.clone(), input.width, input.height)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(match input.tree() {
+            Some(&(seconds, _)) => TreeSearch::Found(seconds),
+            None => TreeSearch::NoTree,
+        })
+    }
+
+    fn visualize(input: &Self::Input) -> Option<String> {
+        let (_, robots) = input.tree()?;
+        Some(display_grid(robots, input.width, input.height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = parse_bathroom(EXAMPLE, 11, 7).unwrap();
+        assert_eq!(Day14::part1(&input), 12);
+    }
+
+    // The example robots never form a christmas tree
+    #[test]
+    fn part2_example() {
+        let input = parse_bathroom(EXAMPLE, 11, 7).unwrap();
+        assert_eq!(Day14::part2(&input), Ok(TreeSearch::NoTree));
+        assert_eq!(Day14::visualize(&input), None);
+    }
+
+    #[test]
+    fn robots_start_inside_the_bathroom() {
+        let input = Day14::parse("p=0,0 v=1,1\np=100,4 v=1,1\n").unwrap();
+        assert_eq!((input.width, input.height), BATHROOM_SIZE);
+        let err = Day14::parse("p=101,0 v=1,1\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: expected an x position between 0 and 100, found `101`"
+        );
+        let err = parse_bathroom("p=3,7 v=1,1\n", 11, 7).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 5: expected a y position between 0 and 6, found `7`"
+        );
+    }
+}
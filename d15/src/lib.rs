@@ -0,0 +1,309 @@
+use aoc_core::{
+    parse::{self, Cursor, Found},
+    ParseError, Solution, SolveError,
+};
+use aoc_grid::{Direction, Grid, Point};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Wall,
+    Box,
+    Empty,
+}
+
+impl Tile {
+    fn symbol(&self) -> char {
+        match self {
+            Tile::Box => 'O',
+            Tile::Wall => '#',
+            Tile::Empty => '.',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartTwoTile {
+    Wall,
+    LeftBox,
+    RightBox,
+    Empty,
+}
+
+impl PartTwoTile {
+    fn symbol(&self) -> char {
+        match self {
+            PartTwoTile::LeftBox => '[',
+            PartTwoTile::RightBox => ']',
+            PartTwoTile::Wall => '#',
+            PartTwoTile::Empty => '.',
+        }
+    }
+}
+
+pub fn display_map(map: &Grid<Tile>, robot_position: Point<usize>) -> String {
+    let mut rendered = map.map(Tile::symbol);
+    rendered[robot_position] = '@';
+    rendered.to_string()
+}
+
+pub fn p2_display_map(map: &Grid<PartTwoTile>, robot_position: Point<usize>) -> String {
+    let mut rendered = map.map(PartTwoTile::symbol);
+    rendered[robot_position] = '@';
+    rendered.to_string()
+}
+
+fn is_valid_move(robot_position: Point<usize>, direction: Direction, map: &Grid<Tile>) -> bool {
+    let Some(new_position) = map.step(robot_position, direction) else {
+        return false;
+    };
+    // Check if instruction is valid
+    match map[new_position] {
+        Tile::Wall => false,
+        Tile::Empty => true,
+        Tile::Box => is_valid_move(new_position, direction, map),
+    }
+}
+
+fn p2_is_valid_move(
+    robot_position: Point<usize>,
+    direction: Direction,
+    map: &Grid<PartTwoTile>,
+) -> bool {
+    let Some(new_position) = map.step(robot_position, direction) else {
+        return false;
+    };
+    // The other half of a box is always inside the map
+    let right_half = || map.step(new_position, Direction::Right).unwrap();
+    let left_half = || map.step(new_position, Direction::Left).unwrap();
+    // Check if instruction is valid
+    match (map[new_position], direction) {
+        (PartTwoTile::Wall, _) => false,
+        (PartTwoTile::Empty, _) => true,
+        (PartTwoTile::LeftBox, Direction::Up | Direction::Down) => {
+            p2_is_valid_move(new_position, direction, map)
+                && p2_is_valid_move(right_half(), direction, map)
+        }
+        (PartTwoTile::LeftBox, Direction::Right) => p2_is_valid_move(right_half(), direction, map),
+        (PartTwoTile::LeftBox, Direction::Left) => p2_is_valid_move(new_position, direction, map),
+        (PartTwoTile::RightBox, Direction::Up | Direction::Down) => {
+            p2_is_valid_move(new_position, direction, map)
+                && p2_is_valid_move(left_half(), direction, map)
+        }
+        (PartTwoTile::RightBox, Direction::Left) => p2_is_valid_move(left_half(), direction, map),
+        (PartTwoTile::RightBox, Direction::Right) => p2_is_valid_move(new_position, direction, map),
+    }
+}
+
+fn move_object(robot_position: Point<usize>, direction: Direction, map: &mut Grid<PartTwoTile>) {
+    let goal_position = map.step(robot_position, direction).unwrap();
+    let right_half = map.step(goal_position, Direction::Right);
+    let left_half = map.step(goal_position, Direction::Left);
+    // Check if instruction is valid
+    match (map[goal_position], direction) {
+        (PartTwoTile::Wall, _) => panic!("Cannot move object into a wall"),
+        (PartTwoTile::Empty, _) => (),
+        (PartTwoTile::LeftBox, Direction::Right) => {
+            move_object(right_half.unwrap(), direction, map);
+            move_object(goal_position, direction, map);
+        }
+        (PartTwoTile::LeftBox, _) => {
+            move_object(goal_position, direction, map);
+            move_object(right_half.unwrap(), direction, map);
+        }
+        (PartTwoTile::RightBox, Direction::Left) => {
+            move_object(left_half.unwrap(), direction, map);
+            move_object(goal_position, direction, map);
+        }
+        (PartTwoTile::RightBox, _) => {
+            move_object(goal_position, direction, map);
+            move_object(left_half.unwrap(), direction, map);
+        }
+    }
+
+    assert_eq!(map[goal_position], PartTwoTile::Empty);
+    map[goal_position] = map[robot_position];
+    map[robot_position] = PartTwoTile::Empty;
+}
+
+pub struct Warehouse {
+    pub map: Grid<Tile>,
+    pub robot_position: Point<usize>,
+    pub instructions: Vec<Direction>,
+}
+
+/// The map and the robot's instructions are separated by a blank line, the
+/// instructions may span several lines.
+pub fn parse_warehouse(contents: &str) -> Result<Warehouse, ParseError> {
+    let lines: Vec<&str> = contents.lines().collect();
+    let Some(map_height) = lines.iter().position(|line| line.is_empty()) else {
+        return Err(ParseError::end_of_input(
+            lines.len(),
+            "a blank line followed by the instructions",
+        ));
+    };
+
+    let chars = Grid::parse(
+        &lines[..map_height].join("\n"),
+        "`#`, `O`, `.` or `@`",
+        |char| matches!(char, '#' | 'O' | '.' | '@').then_some(char),
+    )?;
+    let robot_position = chars.position(|&char| char == '@').ok_or_else(|| {
+        let blank_line = map_height + 1;
+        ParseError::new(
+            blank_line,
+            1,
+            "the robot (`@`) on the map",
+            Found::EndOfLine,
+        )
+    })?;
+    let map = chars.map(|char| match char {
+        '#' => Tile::Wall,
+        'O' => Tile::Box,
+        _ => Tile::Empty,
+    });
+
+    let mut instructions: Vec<Direction> = Vec::new();
+    for (number, line) in parse::lines(contents).skip(map_height + 1) {
+        let mut cursor = Cursor::new(number, line);
+        while let Some(char) = cursor.peek() {
+            let direction = Direction::from_char(char)
+                .ok_or_else(|| cursor.error("an instruction (`^`, `>`, `v` or `<`)"))?;
+            instructions.push(direction);
+            cursor.next_char();
+        }
+    }
+
+    Ok(Warehouse {
+        map,
+        robot_position,
+        instructions,
+    })
+}
+
+/// Runs the robot through the narrow warehouse and returns the final map and
+/// robot position.
+pub fn simulate(warehouse: &Warehouse) -> (Grid<Tile>, Point<usize>) {
+    let mut map = warehouse.map.clone();
+    let mut robot_position = warehouse.robot_position;
+
+    for &direction in &warehouse.instructions {
+        if !is_valid_move(robot_position, direction, &map) {
+            continue;
+        }
+        let mut new_position = map.step(robot_position, direction).unwrap();
+        let moved_box = map[new_position] == Tile::Box;
+        map[new_position] = Tile::Empty;
+        robot_position = new_position;
+
+        if moved_box {
+            new_position = map.step(new_position, direction).unwrap();
+            while map[new_position] == Tile::Box {
+                new_position = map.step(new_position, direction).unwrap();
+            }
+
+            map[new_position] = Tile::Box;
+        }
+    }
+
+    (map, robot_position)
+}
+
+/// Same as [`simulate`], but in the twice as wide warehouse of part two.
+pub fn simulate_wide(warehouse: &Warehouse) -> (Grid<PartTwoTile>, Point<usize>) {
+    let narrow = &warehouse.map;
+    let mut cells = Vec::with_capacity(narrow.width() * 2 * narrow.height());
+    for (_, tile) in narrow.iter() {
+        cells.extend(match tile {
+            Tile::Wall => [PartTwoTile::Wall, PartTwoTile::Wall],
+            Tile::Box => [PartTwoTile::LeftBox, PartTwoTile::RightBox],
+            Tile::Empty => [PartTwoTile::Empty, PartTwoTile::Empty],
+        });
+    }
+    let mut map = Grid::from_vec(narrow.width() * 2, narrow.height(), cells);
+    let mut robot_position = Point::new(warehouse.robot_position.x * 2, warehouse.robot_position.y);
+
+    for &direction in &warehouse.instructions {
+        if !p2_is_valid_move(robot_position, direction, &map) {
+            continue;
+        }
+
+        // Execute instruction
+        move_object(robot_position, direction, &mut map);
+        robot_position = map.step(robot_position, direction).unwrap();
+    }
+
+    (map, robot_position)
+}
+
+/// Sum of the GPS Coordinates of all tiles matching `is_box`
+pub fn gps_sum<T>(map: &Grid<T>, is_box: impl Fn(&T) -> bool) -> usize {
+    map.iter()
+        .filter(|(_, tile)| is_box(tile))
+        .map(|(position, _)| position.y * 100 + position.x)
+        .sum()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Warehouse;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_warehouse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        let (map, _) = simulate(input);
+        gps_sum(&map, |tile| *tile == Tile::Box)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        let (map, _) = simulate_wide(input);
+        Ok(gps_sum(&map, |tile| *tile == PartTwoTile::LeftBox))
+    }
+
+    fn visualize(input: &Self::Input) -> Option<String> {
+        let (map, robot_position) = simulate(input);
+        let (wide_map, wide_robot_position) = simulate_wide(input);
+        Some(format!(
+            "{}\n\n{}",
+            display_map(&map, robot_position),
+            p2_display_map(&wide_map, wide_robot_position)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/example.txt");
+
+    #[test]
+    fn part1_small_example() {
+        let input = Day15::parse(include_str!("../inputs/example-small.txt")).unwrap();
+        assert_eq!(Day15::part1(&input), 2028);
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(Day15::part1(&input), 10092);
+    }
+
+    #[test]
+    fn part2_small_example() {
+        let input = Day15::parse(include_str!("../inputs/example-wide.txt")).unwrap();
+        assert_eq!(Day15::part2(&input), Ok(618));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(Day15::part2(&input), Ok(9021));
+    }
+}
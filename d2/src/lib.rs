@@ -0,0 +1,358 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+};
+
+use aoc_core::{
+    parse::{self, Cursor},
+    ParseError, Solution, SolveError,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Ascending,
+    Descending,
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Direction::Ascending => write!(f, "ascending"),
+            Direction::Descending => write!(f, "descending"),
+        }
+    }
+}
+
+/// The rule a level breaks, see [`SafetyPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    /// A strict policy doesn't allow a level to repeat the one before it.
+    Unchanged,
+    /// The level goes against the direction of the levels before it.
+    Reversed(Direction),
+    StepTooSmall {
+        step: u32,
+        min: u32,
+    },
+    StepTooLarge {
+        step: u32,
+        max: u32,
+    },
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rule::Unchanged => write!(f, "repeats the previous level"),
+            Rule::Reversed(direction) => {
+                write!(f, "goes against the {direction} levels before it")
+            }
+            Rule::StepTooSmall { step, min } => {
+                write!(f, "changes by {step}, less than the minimum of {min}")
+            }
+            Rule::StepTooLarge { step, max } => {
+                write!(f, "changes by {step}, more than the maximum of {max}")
+            }
+        }
+    }
+}
+
+/// The first level of a report that breaks the policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub index: usize,
+    pub rule: Rule,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "level {} {}", self.index + 1, self.rule)
+    }
+}
+
+/// What makes a report safe: every step between neighbouring levels is
+/// between `min_step` and `max_step`, and all of them go the same way. A
+/// non-strict policy lets a level repeat the one before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    pub min_step: u32,
+    pub max_step: u32,
+    pub strict: bool,
+}
+
+impl Default for SafetyPolicy {
+    /// The puzzle's rules.
+    fn default() -> SafetyPolicy {
+        SafetyPolicy {
+            min_step: 1,
+            max_step: 3,
+            strict: true,
+        }
+    }
+}
+
+impl SafetyPolicy {
+    fn step_rule(&self, from: u32, to: u32) -> Option<Rule> {
+        let step = from.abs_diff(to);
+        if step < self.min_step {
+            Some(Rule::StepTooSmall {
+                step,
+                min: self.min_step,
+            })
+        } else if step > self.max_step {
+            Some(Rule::StepTooLarge {
+                step,
+                max: self.max_step,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Whether a report going in `direction` may step from `from` to `to`.
+    fn allows(&self, direction: Direction, from: u32, to: u32) -> bool {
+        let monotonic = match (to.cmp(&from), direction) {
+            (Ordering::Equal, _) => !self.strict,
+            (Ordering::Greater, Direction::Ascending) => true,
+            (Ordering::Less, Direction::Descending) => true,
+            _ => false,
+        };
+        monotonic && self.step_rule(from, to).is_none()
+    }
+
+    /// The first level breaking the policy. The report's direction is set
+    /// by the first two levels that differ.
+    pub fn first_violation(&self, report: &[u32]) -> Option<Violation> {
+        let mut direction = None;
+        for (index, pair) in report.windows(2).enumerate() {
+            let (from, to) = (pair[0], pair[1]);
+            let going = match to.cmp(&from) {
+                Ordering::Equal => None,
+                Ordering::Greater => Some(Direction::Ascending),
+                Ordering::Less => Some(Direction::Descending),
+            };
+            let rule = match (going, direction) {
+                (None, _) if self.strict => Some(Rule::Unchanged),
+                (Some(going), Some(expected)) if going != expected => {
+                    Some(Rule::Reversed(expected))
+                }
+                _ => {
+                    direction = direction.or(going);
+                    self.step_rule(from, to)
+                }
+            };
+            if let Some(rule) = rule {
+                return Some(Violation {
+                    index: index + 1,
+                    rule,
+                });
+            }
+        }
+        None
+    }
+}
+
+/// Every report needs at least two levels to have a direction.
+pub fn parse_reports(contents: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    parse::lines(contents)
+        .map(|(number, line)| {
+            let mut cursor = Cursor::new(number, line);
+            let mut levels = vec![cursor.number()?];
+            while !cursor.is_at_end() || levels.len() < 2 {
+                cursor.expect(" ")?;
+                levels.push(cursor.number()?);
+            }
+            Ok(levels)
+        })
+        .collect()
+}
+
+/// Fewest levels to remove so `report` only steps in `direction`, as long as
+/// that is at most `max_removals`.
+///
+/// `removals[i]` counts the levels removed before `i` when level `i` is kept.
+/// The level kept before it is at most `max_removals + 1` places back, so
+/// every level looks at that many predecessors: O(n·k) instead of checking
+/// every subset of removals.
+fn removals_for(
+    report: &[u32],
+    policy: &SafetyPolicy,
+    direction: Direction,
+    max_removals: usize,
+) -> Option<Vec<usize>> {
+    let n = report.len();
+    let mut removals: Vec<Option<usize>> = vec![None; n];
+    let mut kept_before: Vec<Option<usize>> = vec![None; n];
+    let mut best: Option<(usize, usize)> = None;
+
+    for i in 0..n {
+        // Start the report at `i`, dropping everything before it
+        if i <= max_removals {
+            removals[i] = Some(i);
+        }
+        for j in i.saturating_sub(max_removals + 1)..i {
+            let Some(before) = removals[j] else {
+                continue;
+            };
+            let total = before + (i - j - 1);
+            if total <= max_removals
+                && policy.allows(direction, report[j], report[i])
+                && removals[i].is_none_or(|current| total < current)
+            {
+                removals[i] = Some(total);
+                kept_before[i] = Some(j);
+            }
+        }
+
+        // End the report at `i`, dropping everything after it
+        if let Some(before) = removals[i] {
+            let total = before + (n - 1 - i);
+            if total <= max_removals && best.is_none_or(|(fewest, _)| total < fewest) {
+                best = Some((total, i));
+            }
+        }
+    }
+
+    let (_, last) = best?;
+    let mut kept = vec![false; n];
+    let mut level = Some(last);
+    while let Some(i) = level {
+        kept[i] = true;
+        level = kept_before[i];
+    }
+    Some((0..n).filter(|&i| !kept[i]).collect())
+}
+
+/// Indices of the fewest levels to remove to make `report` safe under
+/// `policy`, `None` if that takes more than `max_removals`.
+pub fn dampen(report: &[u32], policy: &SafetyPolicy, max_removals: usize) -> Option<Vec<usize>> {
+    let ascending = removals_for(report, policy, Direction::Ascending, max_removals);
+    let descending = removals_for(report, policy, Direction::Descending, max_removals);
+    match (ascending, descending) {
+        (Some(ascending), Some(descending)) if descending.len() < ascending.len() => {
+            Some(descending)
+        }
+        (Some(ascending), _) => Some(ascending),
+        (None, descending) => descending,
+    }
+}
+
+pub fn report_safe(report: &[u32], policy: &SafetyPolicy, max_removals: usize) -> bool {
+    dampen(report, policy, max_removals).is_some()
+}
+
+pub fn safe_count(reports: &[Vec<u32>], policy: &SafetyPolicy, max_removals: usize) -> usize {
+    reports
+        .iter()
+        .filter(|report| report_safe(report, policy, max_removals))
+        .count()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<u32>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_reports(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        safe_count(input, &SafetyPolicy::default(), 0)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(safe_count(input, &SafetyPolicy::default(), 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part1(&input), 2);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part2(&input), Ok(4));
+    }
+
+    #[test]
+    fn dampen_names_the_removed_levels() {
+        let policy = SafetyPolicy::default();
+        assert_eq!(dampen(&[7, 6, 4, 2, 1], &policy, 1), Some(vec![]));
+        // Removing the 3 would work as well as removing the 2
+        assert_eq!(dampen(&[1, 3, 2, 4, 5], &policy, 1), Some(vec![2]));
+        assert_eq!(dampen(&[8, 6, 4, 4, 1], &policy, 1), Some(vec![3]));
+        assert_eq!(dampen(&[1, 2, 7, 8, 9], &policy, 1), None);
+        // The first level can go as well as the last
+        assert_eq!(dampen(&[9, 1, 2, 3], &policy, 1), Some(vec![0]));
+        assert_eq!(dampen(&[1, 2, 3, 9], &policy, 1), Some(vec![3]));
+    }
+
+    #[test]
+    fn larger_removal_budgets() {
+        let policy = SafetyPolicy::default();
+        let report = [1, 9, 2, 9, 3, 4];
+        assert_eq!(dampen(&report, &policy, 1), None);
+        assert_eq!(dampen(&report, &policy, 2), Some(vec![1, 3]));
+        assert_eq!(safe_count(&[vec![1, 2, 7, 8, 9]], &policy, 2), 1);
+    }
+
+    #[test]
+    fn explains_the_first_violation() {
+        let policy = SafetyPolicy::default();
+        let violation = |report: &[u32]| policy.first_violation(report).map(|v| (v.index, v.rule));
+        assert_eq!(violation(&[7, 6, 4, 2, 1]), None);
+        assert_eq!(
+            violation(&[1, 2, 7, 8, 9]),
+            Some((2, Rule::StepTooLarge { step: 5, max: 3 }))
+        );
+        assert_eq!(
+            violation(&[1, 3, 2, 4, 5]),
+            Some((2, Rule::Reversed(Direction::Ascending)))
+        );
+        assert_eq!(violation(&[8, 6, 4, 4, 1]), Some((3, Rule::Unchanged)));
+        assert_eq!(
+            policy.first_violation(&[1, 2, 7]).unwrap().to_string(),
+            "level 3 changes by 5, more than the maximum of 3"
+        );
+    }
+
+    #[test]
+    fn non_strict_policies_allow_repeated_levels() {
+        let policy = SafetyPolicy {
+            min_step: 0,
+            max_step: 5,
+            strict: false,
+        };
+        assert_eq!(policy.first_violation(&[4, 4, 2, 2, 1]), None);
+        assert_eq!(policy.first_violation(&[1, 2, 7, 8, 9]), None);
+        assert_eq!(
+            policy.first_violation(&[3, 3, 4, 1]).map(|v| v.rule),
+            Some(Rule::Reversed(Direction::Ascending))
+        );
+        assert_eq!(dampen(&[3, 3, 4, 1], &policy, 1), Some(vec![3]));
+
+        // Repeats are monotonic, but still have to respect the minimum step
+        let policy = SafetyPolicy {
+            strict: false,
+            ..SafetyPolicy::default()
+        };
+        assert_eq!(
+            policy.first_violation(&[8, 6, 4, 4, 1]).map(|v| v.rule),
+            Some(Rule::StepTooSmall { step: 0, min: 1 })
+        );
+    }
+}
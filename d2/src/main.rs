@@ -1,78 +1,70 @@
-use std::fs;
+use std::process::ExitCode;
 
-#[derive(PartialEq, Eq)]
-enum Direction {
-    ASCENDING,
-    DESCENDING,
-}
+use aoc_core::cli::{self, DayArgs};
+use clap::Parser;
+use d2::{dampen, Day2, SafetyPolicy};
 
-fn report_safe(report: Vec<u32>, dampening_active: bool) -> bool {
-    let mut previous_level: u32 = report[0];
-    let direction: Direction = if report[0] < report[1] {
-        Direction::ASCENDING
-    } else {
-        Direction::DESCENDING
-    };
+#[derive(Parser)]
+#[command(about = "Solves day 2 of Advent of Code 2024")]
+struct Cli {
+    #[command(flatten)]
+    day: DayArgs,
 
-    for &level in report[1..].iter() {
-        let difference = level.abs_diff(previous_level);
+    /// Count reports that are safe after removing up to this many levels, listing the levels removed
+    #[arg(long, value_name = "K")]
+    max_removals: Option<usize>,
 
-        if difference < 1
-            || difference > 3
-            || (level > previous_level && direction == Direction::DESCENDING)
-            || (level < previous_level && direction == Direction::ASCENDING)
-        {
-            if dampening_active {
-                // Remove each level and check if it is safe
-                for i in 0..(report.len()) {
-                    let report_without_level: Vec<_> = report
-                        .iter()
-                        .enumerate()
-                        .filter(|(index, _)| *index != i)
-                        .map(|(_, element)| *element)
-                        .collect();
-                    // Check if safe without dampening
-                    if report_safe(report_without_level, false) {
-                        return true;
-                    }
-                }
-            }
-            return false;
-        }
-        previous_level = level;
-    }
-    true
+    /// Smallest allowed change between neighbouring levels
+    #[arg(long, default_value_t = 1)]
+    min_step: u32,
+
+    /// Largest allowed change between neighbouring levels
+    #[arg(long, default_value_t = 3)]
+    max_step: u32,
+
+    /// Let a level repeat the one before it
+    #[arg(long)]
+    non_strict: bool,
+
+    /// Print the first level breaking the rules for every unsafe report
+    #[arg(long)]
+    explain: bool,
 }
 
-fn main() {
-    const INPUT_FILE: &str = "inputs/input.txt";
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let policy = SafetyPolicy {
+        min_step: cli.min_step,
+        max_step: cli.max_step,
+        strict: !cli.non_strict,
+    };
+    if cli.max_removals.is_none() && !cli.explain && policy == SafetyPolicy::default() {
+        return cli::run::<Day2>(&cli.day);
+    }
 
-    let contents = fs::read_to_string(INPUT_FILE).expect("Unable to read file.");
-    let mut safe_count: u32 = 0;
-    for line in contents.lines() {
-        let is_safe = report_safe(
-            line.split(" ")
-                .map(|string| string.parse().expect("Invalid input"))
-                .collect(),
-            false,
-        );
-        if is_safe {
-            safe_count += 1;
+    let Some(reports) = cli::load::<Day2>(&cli.day.input) else {
+        return ExitCode::FAILURE;
+    };
+    if cli.explain {
+        for (line, report) in reports.iter().enumerate() {
+            if let Some(violation) = policy.first_violation(report) {
+                println!("Report {}: {violation}", line + 1);
+            }
         }
     }
-    println!("Safe count: {safe_count}");
 
-    let mut safe_count_dampened: u32 = 0;
-    for line in contents.lines() {
-        let is_safe = report_safe(
-            line.split(" ")
-                .map(|string| string.parse().expect("Invalid input"))
-                .collect(),
-            true,
-        );
-        if is_safe {
-            safe_count_dampened += 1;
+    let max_removals = cli.max_removals.unwrap_or(0);
+    let mut safe = 0;
+    for (line, report) in reports.iter().enumerate() {
+        let Some(removed) = dampen(report, &policy, max_removals) else {
+            continue;
+        };
+        safe += 1;
+        if !removed.is_empty() {
+            let levels: Vec<String> = removed.iter().map(|i| (i + 1).to_string()).collect();
+            println!("Report {}: remove level {}", line + 1, levels.join(", "));
         }
     }
-    println!("Safe count (dampened): {safe_count_dampened}");
+    println!("{safe} reports are safe with a removal budget of {max_removals}");
+    ExitCode::SUCCESS
 }
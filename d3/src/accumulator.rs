@@ -0,0 +1,64 @@
+use std::fmt::Display;
+
+use num_bigint::BigInt;
+
+/// A number type the [`Machine`](crate::machine::Machine) sums up into.
+/// Every operation is checked, `None` means the result doesn't fit.
+pub trait Accumulator: Clone + Default + Display {
+    /// Name of the type in error messages.
+    const NAME: &'static str;
+
+    fn from_u32(value: u32) -> Self;
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+}
+
+macro_rules! fixed_width {
+    ($($int:ident),*) => {
+        $(
+            impl Accumulator for $int {
+                const NAME: &'static str = stringify!($int);
+
+                fn from_u32(value: u32) -> $int {
+                    $int::from(value)
+                }
+
+                fn checked_add(&self, other: &$int) -> Option<$int> {
+                    $int::checked_add(*self, *other)
+                }
+
+                fn checked_sub(&self, other: &$int) -> Option<$int> {
+                    $int::checked_sub(*self, *other)
+                }
+
+                fn checked_mul(&self, other: &$int) -> Option<$int> {
+                    $int::checked_mul(*self, *other)
+                }
+            }
+        )*
+    };
+}
+
+fixed_width!(u32, u64, u128);
+
+/// Arbitrary precision, never overflows and may even go below zero.
+impl Accumulator for BigInt {
+    const NAME: &'static str = "big integer";
+
+    fn from_u32(value: u32) -> BigInt {
+        BigInt::from(value)
+    }
+
+    fn checked_add(&self, other: &BigInt) -> Option<BigInt> {
+        Some(self + other)
+    }
+
+    fn checked_sub(&self, other: &BigInt) -> Option<BigInt> {
+        Some(self - other)
+    }
+
+    fn checked_mul(&self, other: &BigInt) -> Option<BigInt> {
+        Some(self * other)
+    }
+}
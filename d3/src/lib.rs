@@ -0,0 +1,276 @@
+pub mod accumulator;
+pub mod machine;
+pub mod scanner;
+
+use std::{
+    fmt::{self, Display},
+    io::{self, BufRead},
+};
+
+use accumulator::Accumulator;
+use aoc_core::{InputError, ParseError, Solution, SolveError};
+use machine::{Machine, Overflow};
+use num_bigint::BigInt;
+use scanner::{Features, Instruction, Scanner, StreamScanner, Token};
+
+/// Chunk size of [`execute_reader`] unless told otherwise.
+pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+#[derive(Debug)]
+pub enum ExecuteError {
+    Io(io::Error),
+    Overflow(Overflow),
+}
+
+impl Display for ExecuteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExecuteError::Io(err) => write!(f, "{err}"),
+            ExecuteError::Overflow(overflow) => write!(f, "{overflow}"),
+        }
+    }
+}
+
+impl std::error::Error for ExecuteError {}
+
+impl From<io::Error> for ExecuteError {
+    fn from(err: io::Error) -> ExecuteError {
+        ExecuteError::Io(err)
+    }
+}
+
+impl From<Overflow> for ExecuteError {
+    fn from(overflow: Overflow) -> ExecuteError {
+        ExecuteError::Overflow(overflow)
+    }
+}
+
+/// Runs every instruction `features` recognize and returns the sum.
+pub fn execute<A: Accumulator>(memory: &str, features: Features) -> Result<A, Overflow> {
+    let mut machine = Machine::default();
+    for token in Scanner::new(memory, features) {
+        machine.execute(&token)?;
+    }
+    Ok(machine.sum)
+}
+
+/// Like [`execute`], but streams the memory in chunks of `chunk_size` bytes
+/// so it never has to fit into memory at once. The machine, and so whether
+/// instructions are enabled, carries over from one chunk to the next.
+pub fn execute_reader<A: Accumulator>(
+    memory: impl BufRead,
+    features: Features,
+    chunk_size: usize,
+) -> Result<A, ExecuteError> {
+    trace_reader(memory, features, chunk_size, |_| {})
+}
+
+/// An instruction [`trace_reader`] executed.
+pub struct Step<'a, A> {
+    pub token: &'a Token,
+    /// Whether instructions were enabled when it was reached.
+    pub enabled: bool,
+    /// The sum after executing it.
+    pub sum: &'a A,
+}
+
+/// [`execute_reader`], calling `trace` after every instruction.
+pub fn trace_reader<A: Accumulator>(
+    memory: impl BufRead,
+    features: Features,
+    chunk_size: usize,
+    mut trace: impl FnMut(Step<A>),
+) -> Result<A, ExecuteError> {
+    let mut machine = Machine::default();
+    for token in StreamScanner::new(memory, features, chunk_size) {
+        let token = token?;
+        let enabled = machine.enabled;
+        machine.execute(&token)?;
+        trace(Step {
+            token: &token,
+            enabled,
+            sum: &machine.sum,
+        });
+    }
+    Ok(machine.sum)
+}
+
+/// The answers of both parts. The puzzle sums them up in big integers, which
+/// can't overflow however large the dump is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sums<A = BigInt> {
+    /// Every `mul(a,b)`.
+    pub part1: A,
+    /// `mul(a,b)` while enabled by `do()` and `don't()`.
+    pub part2: A,
+}
+
+/// Sums up both parts in `A` in a single pass over the memory, streamed in
+/// chunks of `chunk_size` bytes.
+pub fn sum_parts<A: Accumulator>(
+    memory: impl BufRead,
+    chunk_size: usize,
+) -> Result<Sums<A>, ExecuteError> {
+    let mut all = Machine::<A>::default();
+    let mut conditional = Machine::<A>::default();
+    for token in StreamScanner::new(memory, Features::PART2, chunk_size) {
+        let token = token?;
+        // Part 1 doesn't know about `do()` and `don't()`
+        if matches!(token.instruction, Instruction::Mul(_)) {
+            all.execute(&token)?;
+        }
+        conditional.execute(&token)?;
+    }
+    Ok(Sums {
+        part1: all.sum,
+        part2: conditional.sum,
+    })
+}
+
+/// [`sum_parts`] in big integers, which leaves reading as the only failure.
+fn sum_big(memory: impl BufRead) -> io::Result<Sums> {
+    sum_parts(memory, DEFAULT_CHUNK_SIZE).map_err(|err| match err {
+        ExecuteError::Io(err) => err,
+        ExecuteError::Overflow(_) => unreachable!("big integers don't overflow"),
+    })
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Sums;
+    type Part1 = BigInt;
+    type Part2 = BigInt;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // Corrupted memory is the puzzle, so any input is valid
+        Ok(sum_big(input.as_bytes()).expect("reading a string can't fail"))
+    }
+
+    /// Streams the memory, so dumps of any size are solved in constant
+    /// memory.
+    fn parse_reader(reader: &mut dyn BufRead) -> Result<Self::Input, InputError> {
+        Ok(sum_big(reader)?)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        input.part1.clone()
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(input.part2.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_example() {
+        let input = Day3::parse(include_str!("../inputs/example.txt")).unwrap();
+        assert_eq!(Day3::part1(&input), BigInt::from(161));
+    }
+
+    #[test]
+    fn part2_example() {
+        // Part 2 comes with its own example containing `do()` and `don't()`
+        let input = Day3::parse(include_str!("../inputs/example2.txt")).unwrap();
+        assert_eq!(Day3::part2(&input), Ok(BigInt::from(48)));
+    }
+
+    #[test]
+    fn streams_both_parts_in_one_pass() {
+        let memory = include_str!("../inputs/example2.txt");
+        let expected = Sums {
+            part1: BigInt::from(161),
+            part2: BigInt::from(48),
+        };
+        assert_eq!(sum_parts(memory.as_bytes(), 7).unwrap(), expected);
+        let input = Day3::parse_reader(&mut memory.as_bytes()).unwrap();
+        assert_eq!(input, expected);
+
+        // Narrow accumulators sum up the same instructions
+        let sums: Sums<u32> = sum_parts(memory.as_bytes(), 7).unwrap();
+        assert_eq!((sums.part1, sums.part2), (161, 48));
+        // 4303 times 999 * 999 still fits, the next one doesn't
+        let memory = "mul(999,999)".repeat(4304);
+        let overflow = sum_parts::<u32>(memory.as_bytes(), 7).unwrap_err();
+        assert!(matches!(
+            overflow,
+            ExecuteError::Overflow(Overflow { offset: 51636, .. })
+        ));
+    }
+
+    #[test]
+    fn disabled_instructions_are_skipped() {
+        let features = Features {
+            conditionals: true,
+            add: true,
+            sub: true,
+            variadic_mul: true,
+        };
+        let memory = "mul(2,3,4)sub(10,2)don't()add(100,1)do()add(1,1)";
+        assert_eq!(execute::<u64>(memory, features), Ok(24 + 8 + 2));
+    }
+
+    #[test]
+    fn enable_state_carries_across_chunks() {
+        let memory = include_str!("../inputs/example2.txt");
+        for chunk_size in [1, 3, 20] {
+            let sum: u32 = execute_reader(memory.as_bytes(), Features::PART2, chunk_size).unwrap();
+            assert_eq!(sum, 48);
+        }
+    }
+
+    #[test]
+    fn overflow_names_the_instruction() {
+        let features = Features {
+            variadic_mul: true,
+            sub: true,
+            ..Features::PART1
+        };
+        let memory = "mul(999,999)..mul(999,999,999,999)";
+        let overflow = execute::<u32>(memory, features).unwrap_err();
+        assert_eq!((overflow.offset, overflow.accumulator), (14, "u32"));
+        assert_eq!(
+            execute::<u64>(memory, features),
+            Ok(999 * 999 + 999 * 999 * 999 * 999)
+        );
+
+        // Unsigned sums can't go below zero, big integers can
+        let overflow = execute::<u128>("mul(1,1)sub(1,3)", features).unwrap_err();
+        assert_eq!(overflow.offset, 8);
+        let sum: BigInt = execute("mul(1,1)sub(1,3)", features).unwrap();
+        assert_eq!(sum, BigInt::from(-1));
+    }
+
+    #[test]
+    fn trace_records_every_instruction() {
+        let memory = include_str!("../inputs/example2.txt");
+        let mut steps = Vec::new();
+        let sum: u64 = trace_reader(memory.as_bytes(), Features::PART2, 16, |step| {
+            steps.push((
+                step.token.offset,
+                step.token.text.clone(),
+                step.enabled,
+                *step.sum,
+            ));
+        })
+        .unwrap();
+        assert_eq!(sum, 48);
+        assert_eq!(
+            steps,
+            [
+                (1, String::from("mul(2,4)"), true, 8),
+                (20, String::from("don't()"), true, 8),
+                (28, String::from("mul(5,5)"), false, 8),
+                (48, String::from("mul(11,8)"), false, 8),
+                (59, String::from("do()"), false, 8),
+                (64, String::from("mul(8,5)"), true, 48),
+            ]
+        );
+    }
+}
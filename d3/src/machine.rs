@@ -0,0 +1,83 @@
+use std::fmt::{self, Display};
+
+use crate::{
+    accumulator::Accumulator,
+    scanner::{Instruction, Token},
+};
+
+/// The sum no longer fits into the accumulator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    /// Byte offset of the instruction that overflowed.
+    pub offset: usize,
+    /// Name of the accumulator type.
+    pub accumulator: &'static str,
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the instruction at byte {} takes the sum out of the range of {}",
+            self.offset, self.accumulator
+        )
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Runs instructions one after another. `don't()` disables every
+/// arithmetic instruction until the next `do()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine<A> {
+    pub enabled: bool,
+    pub sum: A,
+}
+
+impl<A: Accumulator> Default for Machine<A> {
+    fn default() -> Machine<A> {
+        Machine {
+            enabled: true,
+            sum: A::default(),
+        }
+    }
+}
+
+impl<A: Accumulator> Machine<A> {
+    /// Adds the result of an enabled `mul`, `add` or `sub` to the sum. On
+    /// overflow the sum is left as it was.
+    pub fn execute(&mut self, token: &Token) -> Result<(), Overflow> {
+        let sum = match token.instruction {
+            Instruction::Do => {
+                self.enabled = true;
+                return Ok(());
+            }
+            Instruction::Dont => {
+                self.enabled = false;
+                return Ok(());
+            }
+            _ if !self.enabled => return Ok(()),
+            Instruction::Mul(ref arguments) => arguments
+                .iter()
+                .try_fold(A::from_u32(1), |product, &argument| {
+                    product.checked_mul(&A::from_u32(argument))
+                })
+                .and_then(|product| self.sum.checked_add(&product)),
+            Instruction::Add(a, b) => self
+                .sum
+                .checked_add(&A::from_u32(a))
+                .and_then(|sum| sum.checked_add(&A::from_u32(b))),
+            // Adding first keeps unsigned sums from dipping below zero
+            // on the way
+            Instruction::Sub(a, b) => self
+                .sum
+                .checked_add(&A::from_u32(a))
+                .and_then(|sum| sum.checked_sub(&A::from_u32(b))),
+        };
+        self.sum = sum.ok_or(Overflow {
+            offset: token.offset,
+            accumulator: A::NAME,
+        })?;
+        Ok(())
+    }
+}
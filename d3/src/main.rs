@@ -1,50 +1,200 @@
-use std::fs;
-
-use regex::Regex;
-
-fn multiply_all(input: &str) -> u32 {
-    let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
-    let mut sum: u32 = 0;
-
-    for capture in re.captures_iter(input) {
-        sum += capture[1].parse::<u32>().unwrap() * capture[2].parse::<u32>().unwrap();
-        // Capture group 0 is the entire pattern, 1 and 2 the capture groups
-        // println!(
-        //     "{} {} {}",
-        //     capture[0].parse::<String>().unwrap(),
-        //     capture[1].parse::<String>().unwrap(),
-        //     capture[2].parse::<String>().unwrap()
-        // );
-    }
+use std::{
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{
+    cli::{self, DayArgs, InputArgs},
+    table, DayResult, PartResult, Solution,
+};
+use clap::{Parser, ValueEnum};
+use d3::{
+    accumulator::Accumulator, scanner::Features, sum_parts, trace_reader, Day3, ExecuteError, Step,
+    DEFAULT_CHUNK_SIZE,
+};
+use num_bigint::BigInt;
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum Feature {
+    /// `do()` and `don't()`
+    Conditionals,
+    /// `add(a,b)`
+    Add,
+    /// `sub(a,b)`
+    Sub,
+    /// `mul` with more than two arguments
+    VariadicMul,
+}
+
+/// Number types to sum up into.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum Width {
+    U32,
+    U64,
+    U128,
+    /// Arbitrary precision
+    Big,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum Trace {
+    /// Aligned columns followed by the sum
+    Table,
+    /// Comma separated values only, for diffing runs
+    Csv,
+}
+
+#[derive(Parser)]
+#[command(about = "Solves day 3 of Advent of Code 2024")]
+struct Cli {
+    #[command(flatten)]
+    day: DayArgs,
+
+    /// Instead of the two parts, print a single sum of `mul(a,b)` and these instructions, may be repeated
+    #[arg(long, value_enum, value_name = "FEATURE")]
+    feature: Vec<Feature>,
+
+    /// Stream the memory in chunks of this many bytes (default: 65536)
+    #[arg(long, value_name = "BYTES")]
+    chunk_size: Option<usize>,
+
+    /// Number type the sums are kept in, overflowing it is an error (default: big for the parts, u64 for a single sum)
+    #[arg(long, value_enum, value_name = "TYPE")]
+    accumulator: Option<Width>,
+
+    /// Instead of the two parts, list every instruction of the single sum with its byte offset, whether it was enabled and the running sum
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    trace: Option<Trace>,
+}
 
-    sum
+/// Quotes `field` if it contains a comma or quote.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
 }
 
-fn conditional_multiply(input: &str) -> u32 {
-    let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)|do\(\)|don't\(\)").unwrap();
-    let mut sum: u32 = 0;
-    let mut active = true;
+/// Solves both parts like a normal run, summing them up in `A` and streaming
+/// the memory in chunks of `chunk_size` bytes.
+fn solve_parts<A: Accumulator>(args: &DayArgs, chunk_size: usize) -> ExitCode {
+    let start = Instant::now();
+    let sums = cli::open(&args.input)
+        .map_err(ExecuteError::from)
+        .and_then(|memory| sum_parts::<A>(memory, chunk_size));
+    let parse_time = start.elapsed();
+    let sums = match sums {
+        Ok(sums) => sums,
+        Err(err) => return report(&args.input, err),
+    };
+
+    // Both parts are summed up in the same pass, which is all the time taken
+    let part = |part, answer: A| PartResult {
+        part,
+        answer: answer.to_string(),
+        elapsed: Duration::ZERO,
+    };
+    let result = DayResult {
+        day: Day3::DAY,
+        parse_time,
+        parts: [part(1, sums.part1), part(2, sums.part2)],
+        visualization: None,
+    };
+    println!("{}", args.output.render(&result));
+    ExitCode::SUCCESS
+}
 
-    for capture in re.captures_iter(input) {
-        if &capture[0] == "do()" {
-            active = true;
-        } else if &capture[0] == "don't()" {
-            active = false;
-        } else if active {
-            sum += &capture[1].parse::<u32>().unwrap() * &capture[2].parse::<u32>().unwrap();
+fn report(input: &InputArgs, err: ExecuteError) -> ExitCode {
+    match err {
+        ExecuteError::Io(err) => {
+            eprintln!("Unable to read {}: {err}", cli::describe(input));
+        }
+        ExecuteError::Overflow(overflow) => {
+            eprintln!("{}: {overflow}", cli::describe(input));
         }
     }
+    ExitCode::FAILURE
+}
 
-    sum
+/// Streams the memory and sums it up in `A`, printing the trace if asked
+/// to. A CSV trace is printed as it goes, a table once it is complete (or
+/// an overflow ended it).
+fn stream_sum<A: Accumulator>(
+    input: &InputArgs,
+    features: Features,
+    chunk_size: usize,
+    trace: Option<Trace>,
+) -> Result<String, ExecuteError> {
+    let memory = cli::open(input)?;
+    if trace == Some(Trace::Csv) {
+        println!("offset,instruction,enabled,sum");
+    }
+
+    let mut rows = Vec::new();
+    let sum = trace_reader(memory, features, chunk_size, |step: Step<A>| match trace {
+        Some(Trace::Csv) => println!(
+            "{},{},{},{}",
+            step.token.offset,
+            csv_field(&step.token.text),
+            step.enabled,
+            step.sum
+        ),
+        Some(Trace::Table) => rows.push([
+            step.token.offset.to_string(),
+            step.token.text.clone(),
+            String::from(if step.enabled { "yes" } else { "no" }),
+            step.sum.to_string(),
+        ]),
+        None => {}
+    });
+
+    if trace == Some(Trace::Table) {
+        print!(
+            "{}",
+            table::render(
+                ["Offset", "Instruction", "Enabled", "Sum"],
+                &rows,
+                [true, false, false, true],
+            )
+        );
+    }
+    Ok(sum?.to_string())
 }
 
-fn main() {
-    const INPUT_FILE: &str = "inputs/input.txt";
-    let contents = fs::read_to_string(INPUT_FILE).expect("Unable to read file");
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let chunk_size = cli.chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE);
+    if cli.feature.is_empty() && cli.trace.is_none() {
+        return match cli.accumulator {
+            None if cli.chunk_size.is_none() => cli::run::<Day3>(&cli.day),
+            None | Some(Width::Big) => solve_parts::<BigInt>(&cli.day, chunk_size),
+            Some(Width::U32) => solve_parts::<u32>(&cli.day, chunk_size),
+            Some(Width::U64) => solve_parts::<u64>(&cli.day, chunk_size),
+            Some(Width::U128) => solve_parts::<u128>(&cli.day, chunk_size),
+        };
+    }
 
-    println!(
-        "Unconditional: {}\nConditional: {}",
-        multiply_all(contents.as_str()),
-        conditional_multiply(contents.as_str())
-    );
+    let features = Features {
+        conditionals: cli.feature.contains(&Feature::Conditionals),
+        add: cli.feature.contains(&Feature::Add),
+        sub: cli.feature.contains(&Feature::Sub),
+        variadic_mul: cli.feature.contains(&Feature::VariadicMul),
+    };
+    let input = &cli.day.input;
+    let sum = match cli.accumulator.unwrap_or(Width::U64) {
+        Width::U32 => stream_sum::<u32>(input, features, chunk_size, cli.trace),
+        Width::U64 => stream_sum::<u64>(input, features, chunk_size, cli.trace),
+        Width::U128 => stream_sum::<u128>(input, features, chunk_size, cli.trace),
+        Width::Big => stream_sum::<BigInt>(input, features, chunk_size, cli.trace),
+    };
+    match sum {
+        Ok(sum) => {
+            if cli.trace != Some(Trace::Csv) {
+                println!("Sum: {sum}");
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => report(input, err),
+    }
 }
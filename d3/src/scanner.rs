@@ -0,0 +1,383 @@
+use std::io::{self, BufRead};
+
+/// An instruction found in the corrupted memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    /// `mul(a,b)`, or `mul(a,b,c,…)` with [`Features::variadic_mul`].
+    Mul(Vec<u32>),
+    /// `add(a,b)`
+    Add(u32, u32),
+    /// `sub(a,b)`
+    Sub(u32, u32),
+    /// `do()`
+    Do,
+    /// `don't()`
+    Dont,
+}
+
+/// Which instructions the scanner recognizes. `mul(a,b)` always is,
+/// everything else is ignored like any other corruption unless switched on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Features {
+    /// `do()` and `don't()`
+    pub conditionals: bool,
+    pub add: bool,
+    pub sub: bool,
+    /// `mul` with more than two arguments
+    pub variadic_mul: bool,
+}
+
+impl Features {
+    /// Only `mul(a,b)`.
+    pub const PART1: Features = Features {
+        conditionals: false,
+        add: false,
+        sub: false,
+        variadic_mul: false,
+    };
+
+    pub const PART2: Features = Features {
+        conditionals: true,
+        ..Features::PART1
+    };
+}
+
+/// An instruction and where it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    /// Byte offset of the instruction in the memory.
+    pub offset: usize,
+    /// The instruction as written in the memory.
+    pub text: String,
+    pub instruction: Instruction,
+}
+
+impl Token {
+    fn new(offset: usize, text: &[u8], instruction: Instruction) -> Token {
+        Token {
+            offset,
+            // Instructions are made of ASCII only
+            text: text.iter().map(|&byte| char::from(byte)).collect(),
+            instruction,
+        }
+    }
+}
+
+/// What [`scan`] found at the start of its input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Scan {
+    /// An instruction and the length of its text.
+    Match(Instruction, usize),
+    /// No instruction starts here.
+    NoMatch,
+    /// The input ends before it is clear whether an instruction starts
+    /// here, more of it could still complete one.
+    Incomplete,
+}
+
+/// Why no instruction was read, so the helpers below can use `?`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Miss {
+    NoMatch,
+    Incomplete,
+}
+
+fn literal(input: &[u8], literal: &[u8]) -> Result<usize, Miss> {
+    if input.starts_with(literal) {
+        Ok(literal.len())
+    } else if literal.starts_with(input) {
+        Err(Miss::Incomplete)
+    } else {
+        Err(Miss::NoMatch)
+    }
+}
+
+/// A 1 to 3 digit number, returning it and the number of digits.
+fn number(input: &[u8]) -> Result<(u32, usize), Miss> {
+    let digits = input
+        .iter()
+        .take(3)
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    match input.get(digits) {
+        // Whatever follows decides if this is a number
+        None => return Err(Miss::Incomplete),
+        Some(byte) if byte.is_ascii_digit() => return Err(Miss::NoMatch),
+        _ if digits == 0 => return Err(Miss::NoMatch),
+        _ => {}
+    }
+    let value = input[..digits]
+        .iter()
+        .fold(0, |value, digit| value * 10 + u32::from(digit - b'0'));
+    Ok((value, digits))
+}
+
+/// Comma separated numbers up to and including the closing parenthesis,
+/// returning them and the length of the text consumed.
+fn arguments(input: &[u8]) -> Result<(Vec<u32>, usize), Miss> {
+    let mut arguments = Vec::new();
+    let mut len = 0;
+    loop {
+        let (value, digits) = number(&input[len..])?;
+        arguments.push(value);
+        len += digits;
+        match input.get(len) {
+            Some(b',') => len += 1,
+            Some(b')') => return Ok((arguments, len + 1)),
+            Some(_) => return Err(Miss::NoMatch),
+            None => return Err(Miss::Incomplete),
+        }
+    }
+}
+
+/// `name(a,b,…)`, returning the arguments and the length of the text.
+fn call(input: &[u8], name: &[u8]) -> Result<(Vec<u32>, usize), Miss> {
+    let len = literal(input, name)?;
+    let len = len + literal(&input[len..], b"(")?;
+    let (arguments, rest) = arguments(&input[len..])?;
+    Ok((arguments, len + rest))
+}
+
+/// `name(a,b)` with exactly two arguments, like `add` and `sub`.
+fn binary(input: &[u8], name: &[u8]) -> Result<(u32, u32, usize), Miss> {
+    match call(input, name)? {
+        (arguments, len) if arguments.len() == 2 => Ok((arguments[0], arguments[1], len)),
+        _ => Err(Miss::NoMatch),
+    }
+}
+
+fn instruction_at(input: &[u8], features: &Features) -> Result<(Instruction, usize), Miss> {
+    match input.first().ok_or(Miss::Incomplete)? {
+        b'm' => {
+            let (arguments, len) = call(input, b"mul")?;
+            if arguments.len() == 2 || features.variadic_mul && arguments.len() > 2 {
+                Ok((Instruction::Mul(arguments), len))
+            } else {
+                Err(Miss::NoMatch)
+            }
+        }
+        b'd' if features.conditionals => match literal(input, b"do()") {
+            Ok(len) => Ok((Instruction::Do, len)),
+            // `do` could still become `don't()`
+            Err(miss) => match literal(input, b"don't()") {
+                Ok(len) => Ok((Instruction::Dont, len)),
+                Err(Miss::NoMatch) => Err(miss),
+                Err(Miss::Incomplete) => Err(Miss::Incomplete),
+            },
+        },
+        b'a' if features.add => {
+            binary(input, b"add").map(|(a, b, len)| (Instruction::Add(a, b), len))
+        }
+        b's' if features.sub => {
+            binary(input, b"sub").map(|(a, b, len)| (Instruction::Sub(a, b), len))
+        }
+        _ => Err(Miss::NoMatch),
+    }
+}
+
+/// Looks for an instruction at the very start of `input`.
+pub fn scan(input: &[u8], features: &Features) -> Scan {
+    match instruction_at(input, features) {
+        Ok((instruction, len)) => Scan::Match(instruction, len),
+        Err(Miss::NoMatch) => Scan::NoMatch,
+        Err(Miss::Incomplete) => Scan::Incomplete,
+    }
+}
+
+/// Finds the instructions in corrupted memory from left to right. Anything
+/// that isn't a complete instruction is skipped one byte at a time, so an
+/// instruction may start in the middle of a broken one (`mumul(1,2)`).
+pub struct Scanner<'a> {
+    memory: &'a [u8],
+    offset: usize,
+    features: Features,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(memory: &'a str, features: Features) -> Scanner<'a> {
+        Scanner {
+            memory: memory.as_bytes(),
+            offset: 0,
+            features,
+        }
+    }
+}
+
+impl Iterator for Scanner<'_> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        while self.offset < self.memory.len() {
+            let offset = self.offset;
+            match scan(&self.memory[offset..], &self.features) {
+                Scan::Match(instruction, len) => {
+                    self.offset += len;
+                    let text = &self.memory[offset..offset + len];
+                    return Some(Token::new(offset, text, instruction));
+                }
+                // Nothing follows the end of the memory to complete it
+                Scan::NoMatch | Scan::Incomplete => self.offset += 1,
+            }
+        }
+        None
+    }
+}
+
+/// Like [`Scanner`], but reads the memory in chunks of `chunk_size` bytes.
+/// Only the unscanned rest of a chunk is kept while an instruction might
+/// continue in the next one, so memory use doesn't grow with the input.
+pub struct StreamScanner<R> {
+    reader: R,
+    features: Features,
+    chunk_size: usize,
+    buffer: Vec<u8>,
+    /// Position in `buffer` scanning continues from.
+    start: usize,
+    /// Byte offset of `buffer[0]` in the memory.
+    offset: usize,
+    at_end: bool,
+}
+
+impl<R: BufRead> StreamScanner<R> {
+    pub fn new(reader: R, features: Features, chunk_size: usize) -> StreamScanner<R> {
+        StreamScanner {
+            reader,
+            features,
+            chunk_size: chunk_size.max(1),
+            buffer: Vec::new(),
+            start: 0,
+            offset: 0,
+            at_end: false,
+        }
+    }
+
+    /// Drops what has been scanned and appends the next chunk.
+    fn refill(&mut self) -> io::Result<()> {
+        self.buffer.drain(..self.start);
+        self.offset += self.start;
+        self.start = 0;
+
+        let available = self.reader.fill_buf()?;
+        let len = available.len().min(self.chunk_size);
+        self.buffer.extend_from_slice(&available[..len]);
+        self.reader.consume(len);
+        self.at_end = len == 0;
+        Ok(())
+    }
+}
+
+impl<R: BufRead> Iterator for StreamScanner<R> {
+    type Item = io::Result<Token>;
+
+    fn next(&mut self) -> Option<io::Result<Token>> {
+        loop {
+            if self.start < self.buffer.len() {
+                match scan(&self.buffer[self.start..], &self.features) {
+                    Scan::Match(instruction, len) => {
+                        let text = &self.buffer[self.start..self.start + len];
+                        let token = Token::new(self.offset + self.start, text, instruction);
+                        self.start += len;
+                        return Some(Ok(token));
+                    }
+                    Scan::Incomplete if !self.at_end => {}
+                    Scan::NoMatch | Scan::Incomplete => {
+                        self.start += 1;
+                        continue;
+                    }
+                }
+            } else if self.at_end {
+                return None;
+            }
+            if let Err(err) = self.refill() {
+                return Some(Err(err));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instructions(memory: &str, features: Features) -> Vec<Instruction> {
+        Scanner::new(memory, features)
+            .map(|token| token.instruction)
+            .collect()
+    }
+
+    #[test]
+    fn skips_corruption() {
+        let tokens: Vec<Token> =
+            Scanner::new("mumul(1,2)mul(1234,5)mul(3,4", Features::PART1).collect();
+        assert_eq!(
+            tokens,
+            [Token {
+                offset: 2,
+                text: String::from("mul(1,2)"),
+                instruction: Instruction::Mul(vec![1, 2])
+            }]
+        );
+    }
+
+    #[test]
+    fn features_switch_instructions_on() {
+        let memory = "don't()add(1,2)sub(5,7)mul(2,3,4)do()";
+        assert_eq!(instructions(memory, Features::PART1), []);
+        assert_eq!(
+            instructions(memory, Features::PART2),
+            [Instruction::Dont, Instruction::Do]
+        );
+
+        let everything = Features {
+            conditionals: true,
+            add: true,
+            sub: true,
+            variadic_mul: true,
+        };
+        assert_eq!(
+            instructions(memory, everything),
+            [
+                Instruction::Dont,
+                Instruction::Add(1, 2),
+                Instruction::Sub(5, 7),
+                Instruction::Mul(vec![2, 3, 4]),
+                Instruction::Do
+            ]
+        );
+        // `add` and `sub` take exactly two arguments
+        assert_eq!(instructions("add(1,2,3)sub(1)", everything), []);
+    }
+
+    #[test]
+    fn incomplete_until_decided() {
+        let features = Features::PART2;
+        for prefix in [
+            "m", "mul(", "mul(12", "mul(123", "mul(1,2", "do", "don", "don't(",
+        ] {
+            assert_eq!(
+                scan(prefix.as_bytes(), &features),
+                Scan::Incomplete,
+                "{prefix}"
+            );
+        }
+        assert_eq!(scan(b"mul(1234", &features), Scan::NoMatch);
+        assert_eq!(scan(b"dox", &features), Scan::NoMatch);
+        assert_eq!(
+            scan(b"mul(1,2)", &features),
+            Scan::Match(Instruction::Mul(vec![1, 2]), 8)
+        );
+    }
+
+    #[test]
+    fn chunks_split_anywhere() {
+        let memory = include_str!("../inputs/example2.txt");
+        let expected: Vec<Token> = Scanner::new(memory, Features::PART2).collect();
+        for chunk_size in 1..=9 {
+            let streamed: Vec<Token> =
+                StreamScanner::new(memory.as_bytes(), Features::PART2, chunk_size)
+                    .map(Result::unwrap)
+                    .collect();
+            assert_eq!(streamed, expected, "chunks of {chunk_size}");
+        }
+    }
+}
@@ -0,0 +1,99 @@
+pub mod render;
+pub mod search;
+pub mod template;
+
+use std::sync::OnceLock;
+
+use aoc_core::{ParseError, Solution, SolveError};
+use aoc_grid::Grid;
+use search::WordSearch;
+use template::{parse_template, TemplateMatcher};
+
+/// One letter per cell. A letter is a grapheme cluster rather than a
+/// `char`, so accents written as combining marks stay with their letter.
+pub fn parse_grid(input: &str) -> Result<Grid<String>, ParseError> {
+    Grid::parse_graphemes(input, "a letter", |letter| Some(letter.to_owned()))
+}
+
+pub fn find_xmas_occurence(grid: &Grid<String>) -> usize {
+    WordSearch::new(["XMAS"]).find(grid).len()
+}
+
+/// The X-MAS shape, two `MAS` crossing in the `A`.
+pub const X_MAS: &str = include_str!("../templates/x-mas.txt");
+
+pub fn find_crossmas_occurences(grid: &Grid<String>) -> usize {
+    // The template is built in, so it is parsed and rotated only once
+    static MATCHER: OnceLock<TemplateMatcher> = OnceLock::new();
+    let matcher = MATCHER.get_or_init(|| {
+        let template = parse_template(X_MAS).expect("X-MAS template is valid");
+        TemplateMatcher::new(template, true, false)
+    });
+    matcher.find(grid).len()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_grid(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        find_xmas_occurence(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(find_crossmas_occurences(input))
+    }
+
+    fn visualize(input: &Self::Input) -> Option<String> {
+        Some(format!(
+            "{}x{} grid detected",
+            input.width(),
+            input.height()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part1(&input), 18);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part2(&input), Ok(9));
+    }
+
+    #[test]
+    fn multibyte_letters_keep_columns_apart() {
+        // A column of Ö in front, half of them written with a combining mark
+        let shifted: String = EXAMPLE
+            .lines()
+            .enumerate()
+            .map(|(y, line)| {
+                let umlaut = if y % 2 == 0 { "Ö" } else { "O\u{308}" };
+                format!("{umlaut}{line}\n")
+            })
+            .collect();
+        let input = Day4::parse(&shifted).unwrap();
+        assert_eq!(input.width(), 11);
+        assert_eq!(Day4::part1(&input), 18);
+        assert_eq!(Day4::part2(&input), Ok(9));
+    }
+}
@@ -1,114 +1,129 @@
-use std::fs;
-
-use regex::Regex;
+use std::{
+    fs,
+    io::{self, IsTerminal},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use aoc_core::cli::{self, DayArgs};
+use clap::{Parser, ValueEnum};
+use d4::{
+    render::{render, Style},
+    search::WordSearch,
+    template::{parse_template, TemplateMatcher},
+    Day4,
+};
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum Render {
+    /// Colour the letters of every match by direction
+    Ansi,
+    /// Show only the letters of matches, everything else as `.`
+    Plain,
+}
 
-fn find_xmas_occurence(contents: &str, width: usize, height: usize) -> usize {
-    const WORD: &str = "XMAS";
+#[derive(Parser)]
+#[command(about = "Solves day 4 of Advent of Code 2024")]
+struct Cli {
+    #[command(flatten)]
+    day: DayArgs,
 
-    println!("{width}x{height} grid detected");
+    /// Solve the grid as a word search for these words instead, listing every match
+    #[arg(long, value_name = "WORD", value_delimiter = ',', num_args = 1..)]
+    words: Vec<String>,
 
-    // Generate all the substrings that would be valid (e.g. horizontal, vertical, diagonal and all of them reversed)
-    let horizontal: Vec<String> = contents
-        .lines()
-        .map(|string| String::from(string))
-        .collect();
+    /// Reprint the grid with the words found highlighted (default: ansi on a terminal, plain otherwise)
+    #[arg(long, value_enum, value_name = "STYLE", num_args = 0..=1)]
+    render: Option<Option<Render>>,
 
-    let mut vertical: Vec<String> = vec![String::new(); width];
-    for line in contents.lines() {
-        for (i, char) in line.chars().enumerate() {
-            vertical[i].push(char);
-        }
-    }
+    /// Count the placements of this template (one letter per cell, `.` matches anything) instead
+    #[arg(long, value_name = "PATH", conflicts_with = "words")]
+    template: Option<PathBuf>,
 
-    let mut diagonal_ltr: Vec<String> = vec![String::new(); height + width - 1];
-    let mut diagonal_rtl: Vec<String> = vec![String::new(); height + width - 1];
-    for x in 0..width {
-        for (y, line) in contents.lines().enumerate() {
-            diagonal_rtl[x + y].push_str(&line[x..x + 1]);
-            diagonal_ltr[((x as isize) - (y as isize) + (height as isize) - 1) as usize]
-                .push_str(&line[x..x + 1]);
-        }
-    }
+    /// Also try the template turned by 90, 180 and 270 degrees
+    #[arg(long, requires = "template")]
+    rotations: bool,
 
-    let horizontal_reversed: Vec<String> = horizontal
-        .iter()
-        .map(|string| string.chars().rev().collect::<String>())
-        .collect();
-    let vertical_reversed: Vec<String> = vertical
-        .iter()
-        .map(|string| string.chars().rev().collect::<String>())
-        .collect();
-    let diagonal_ltr_reversed: Vec<String> = diagonal_ltr
-        .iter()
-        .map(|string| string.chars().rev().collect::<String>())
-        .collect();
-    let diagonal_rtl_reversed: Vec<String> = diagonal_rtl
-        .iter()
-        .map(|string| string.chars().rev().collect::<String>())
-        .collect();
+    /// Also try the template mirrored
+    #[arg(long, requires = "template")]
+    reflections: bool,
 
-    let all_strings = [
-        horizontal,
-        horizontal_reversed,
-        vertical,
-        vertical_reversed,
-        diagonal_ltr,
-        diagonal_ltr_reversed,
-        diagonal_rtl,
-        diagonal_rtl_reversed,
-    ];
-
-    let pattern = regex::escape(WORD);
-    let re = Regex::new(&pattern).unwrap();
+    /// List every placement of the template, not just their number
+    #[arg(long, requires = "template")]
+    list: bool,
+}
 
-    let mut overall_sum = 0;
-    for collection in all_strings {
-        for string in collection {
-            overall_sum += re.find_iter(string.as_str()).count();
+fn match_template(cli: &Cli, path: &Path) -> ExitCode {
+    let source = match fs::read_to_string(path) {
+        Ok(source) => source,
+        Err(err) => {
+            eprintln!("Unable to read {}: {err}", path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+    let template = match parse_template(&source) {
+        Ok(template) => template,
+        Err(err) => {
+            eprintln!("{}", err.render(&path.display().to_string(), &source));
+            return ExitCode::FAILURE;
+        }
+    };
+    let Some(grid) = cli::load::<Day4>(&cli.day.input) else {
+        return ExitCode::FAILURE;
+    };
+
+    let matcher = TemplateMatcher::new(template, cli.rotations, cli.reflections);
+    let placements = matcher.find(&grid);
+    if cli.list {
+        for placement in &placements {
+            println!("{} (variant {})", placement.top_left, placement.variant + 1);
         }
     }
-
-    overall_sum
+    println!("Placements: {}", placements.len());
+    println!("Orientations tried: {}", matcher.variants().len());
+    ExitCode::SUCCESS
 }
 
-fn find_crossmas_occurences(contents: &str, width: usize, height: usize) -> usize {
-    let mut overall_sum = 0;
-    let lines: Vec<&str> = contents.lines().collect();
-
-    for x in 0..width - 2 {
-        for y in 0..height - 2 {
-            let diag_ltr = format!(
-                "{}{}{}",
-                &lines[y][x..x + 1],
-                &lines[y + 1][x + 1..x + 2],
-                &lines[y + 2][x + 2..x + 3]
-            );
-            let diag_rtl = format!(
-                "{}{}{}",
-                &lines[y][x + 2..x + 3],
-                &lines[y + 1][x + 1..x + 2],
-                &lines[y + 2][x..x + 1]
-            );
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let Some(path) = &cli.template {
+        return match_template(&cli, path);
+    }
+    if cli.words.is_empty() && cli.render.is_none() {
+        return cli::run::<Day4>(&cli.day);
+    }
 
-            if (diag_ltr == "MAS" || diag_ltr == "SAM") && (diag_rtl == "MAS" || diag_rtl == "SAM")
-            {
-                overall_sum += 1;
+    let Some(grid) = cli::load::<Day4>(&cli.day.input) else {
+        return ExitCode::FAILURE;
+    };
+    let words = if cli.words.is_empty() {
+        vec![String::from("XMAS")]
+    } else {
+        cli.words
+    };
+    let search = WordSearch::new(words);
+    let matches = search.find(&grid);
+    match cli.render {
+        Some(style) => {
+            let style = match style {
+                Some(Render::Ansi) => Style::Ansi,
+                Some(Render::Plain) => Style::Plain,
+                None if io::stdout().is_terminal() => Style::Ansi,
+                None => Style::Plain,
+            };
+            print!("{}", render(&grid, &search, &matches, style));
+        }
+        None => {
+            for found in &matches {
+                println!(
+                    "{} at {} going {}",
+                    search.words()[found.word],
+                    found.start,
+                    found.direction
+                );
             }
         }
     }
-
-    overall_sum
-}
-
-fn main() {
-    const INPUT_FILE: &str = "inputs/input.txt";
-    let contents = fs::read_to_string(INPUT_FILE).expect("Unable to read file");
-
-    let width = contents.lines().next().unwrap().len();
-    let height = contents.lines().count();
-
-    let overall_sum = find_xmas_occurence(contents.as_str(), width, height);
-    let crossmas_occurences = find_crossmas_occurences(contents.as_str(), width, height);
-
-    println!("Overall sum: {overall_sum}\nCross-MAS Occurences: {crossmas_occurences}");
+    println!("{} matches", matches.len());
+    ExitCode::SUCCESS
 }
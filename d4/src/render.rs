@@ -0,0 +1,71 @@
+use aoc_grid::{Direction8, Grid, Point};
+
+use crate::search::{Match, WordSearch};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// The whole grid with the letters of every match coloured by the
+    /// direction the word runs in, followed by a legend.
+    Ansi,
+    /// Only the letters of matches, everything else as `.` like the
+    /// puzzle's illustration.
+    Plain,
+}
+
+/// ANSI foreground colour of each direction, in the order of
+/// [`Direction8::ALL`].
+const COLOURS: [u8; 8] = [31, 32, 33, 34, 35, 36, 91, 94];
+
+fn paint(text: &str, direction: Direction8) -> String {
+    format!("\x1b[1;{}m{text}\x1b[0m", COLOURS[direction as usize])
+}
+
+/// Reprints `grid` with the cells of `matches` highlighted. Cells shared by
+/// several matches take the colour of the first one.
+pub fn render(grid: &Grid<String>, search: &WordSearch, matches: &[Match], style: Style) -> String {
+    let mut directions: Grid<Option<Direction8>> = grid.map(|_| None);
+    for found in matches {
+        for cell in search.cells(found) {
+            directions[cell].get_or_insert(found.direction);
+        }
+    }
+
+    let mut rendered = String::new();
+    for (y, row) in directions.rows().enumerate() {
+        for (x, direction) in row.iter().enumerate() {
+            let letter = &grid[Point::new(x, y)];
+            match (direction, style) {
+                (Some(direction), Style::Ansi) => rendered += &paint(letter, *direction),
+                (None, Style::Plain) => rendered.push('.'),
+                _ => rendered += letter,
+            }
+        }
+        rendered.push('\n');
+    }
+
+    if style == Style::Ansi {
+        let legend: Vec<String> = Direction8::ALL
+            .into_iter()
+            .map(|direction| paint(direction.name(), direction))
+            .collect();
+        rendered += &legend.join(" ");
+        rendered.push('\n');
+    }
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_keeps_only_matched_letters() {
+        let grid = crate::parse_grid("XMAS\nMXAS\nSAMX\n").unwrap();
+        let search = WordSearch::new(["XMAS"]);
+        let matches = search.find(&grid);
+        assert_eq!(
+            render(&grid, &search, &matches, Style::Plain),
+            "XMAS\n....\nSAMX\n"
+        );
+    }
+}
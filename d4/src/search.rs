@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use aoc_grid::{Direction8, Grid, Point};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A word found in the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Match {
+    /// Index of the word in the list given to [`WordSearch::new`].
+    pub word: usize,
+    pub start: Point<usize>,
+    pub direction: Direction8,
+}
+
+#[derive(Default)]
+struct Node {
+    children: HashMap<String, usize>,
+    /// Words ending here, more than one if the list repeats a word.
+    words: Vec<usize>,
+}
+
+/// Looks for several words at once. The words are stored in a trie, so
+/// walking from a cell in one direction follows every word sharing the
+/// letters seen so far and stops as soon as none does.
+///
+/// Every placement of a word is found once. A word that reads the same
+/// backwards (like `ABA`) covers the same cells in two opposite directions,
+/// it is only reported in the first of them: up, up right, right or down
+/// right. One-letter words have no direction, they are reported once per
+/// cell with [`Direction8::Right`].
+pub struct WordSearch {
+    words: Vec<String>,
+    /// Per word, whether it reads the same backwards.
+    palindromes: Vec<bool>,
+    nodes: Vec<Node>,
+}
+
+impl WordSearch {
+    /// Words are split into grapheme clusters like the grid. Empty words are
+    /// never found.
+    pub fn new(words: impl IntoIterator<Item = impl Into<String>>) -> WordSearch {
+        let words: Vec<String> = words.into_iter().map(Into::into).collect();
+        let mut nodes = vec![Node::default()];
+        for (index, word) in words.iter().enumerate() {
+            if word.is_empty() {
+                continue;
+            }
+            let mut node = 0;
+            for letter in word.graphemes(true) {
+                node = match nodes[node].children.get(letter) {
+                    Some(&child) => child,
+                    None => {
+                        nodes.push(Node::default());
+                        let child = nodes.len() - 1;
+                        nodes[node].children.insert(letter.to_owned(), child);
+                        child
+                    }
+                };
+            }
+            nodes[node].words.push(index);
+        }
+        let palindromes = words
+            .iter()
+            .map(|word| word.graphemes(true).eq(word.graphemes(true).rev()))
+            .collect();
+        WordSearch {
+            words,
+            palindromes,
+            nodes,
+        }
+    }
+
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    /// The cells covered by a match, from its first letter to its last.
+    pub fn cells(&self, found: &Match) -> impl Iterator<Item = Point<usize>> {
+        let delta = found.direction.delta();
+        let start = found.start;
+        (0..self.words[found.word].graphemes(true).count()).map(move |step| {
+            start
+                .checked_add_signed(delta * step as isize)
+                .expect("matches lie inside the grid")
+        })
+    }
+
+    /// Every word starting at every cell in every direction, ordered by
+    /// word, start and direction.
+    pub fn find(&self, grid: &Grid<String>) -> Vec<Match> {
+        let mut matches = Vec::new();
+        for start in grid.positions() {
+            for direction in Direction8::ALL {
+                let mut node = 0;
+                let mut position = Some(start);
+                let mut letters = 0;
+                while let Some(at) = position {
+                    let Some(&child) = self.nodes[node].children.get(&grid[at]) else {
+                        break;
+                    };
+                    node = child;
+                    letters += 1;
+                    let reported = if letters == 1 {
+                        direction == Direction8::Right
+                    } else {
+                        direction < direction.reversed()
+                    };
+                    matches.extend(
+                        self.nodes[node]
+                            .words
+                            .iter()
+                            .filter(|&&word| reported || !self.palindromes[word])
+                            .map(|&word| Match {
+                                word,
+                                start,
+                                direction,
+                            }),
+                    );
+                    position = grid.offset(at, direction.delta());
+                }
+            }
+        }
+        matches.sort_unstable();
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_words_in_every_direction() {
+        let grid = crate::parse_grid("CAT\nOXA\nWOC\n").unwrap();
+        let search = WordSearch::new(["CAT", "COW", "CA", "TAC"]);
+        let found: Vec<(&str, Point<usize>, Direction8)> = search
+            .find(&grid)
+            .into_iter()
+            .map(|m| (search.words()[m.word].as_str(), m.start, m.direction))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("CAT", Point::new(0, 0), Direction8::Right),
+                ("CAT", Point::new(2, 2), Direction8::Up),
+                ("COW", Point::new(0, 0), Direction8::Down),
+                ("COW", Point::new(2, 2), Direction8::Left),
+                ("CA", Point::new(0, 0), Direction8::Right),
+                ("CA", Point::new(2, 2), Direction8::Up),
+                ("TAC", Point::new(2, 0), Direction8::Down),
+                ("TAC", Point::new(2, 0), Direction8::Left),
+            ]
+        );
+    }
+
+    #[test]
+    fn placements_are_found_once() {
+        let grid = crate::parse_grid("ABA\nXAX\n").unwrap();
+        let search = WordSearch::new(["A", "ABA"]);
+        let found: Vec<(usize, Point<usize>, Direction8)> = search
+            .find(&grid)
+            .into_iter()
+            .map(|m| (m.word, m.start, m.direction))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (0, Point::new(0, 0), Direction8::Right),
+                (0, Point::new(1, 1), Direction8::Right),
+                (0, Point::new(2, 0), Direction8::Right),
+                (1, Point::new(0, 0), Direction8::Right),
+            ]
+        );
+    }
+
+    #[test]
+    fn letters_with_combining_marks_are_one_cell() {
+        // The first Ü is precomposed, the second one a U and a combining
+        // diaeresis
+        let grid = crate::parse_grid("GRÜN\nXXU\u{308}X\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (4, 2));
+
+        let search = WordSearch::new(["GRÜN", "ÜU\u{308}"]);
+        let found: Vec<(usize, Point<usize>, Direction8)> = search
+            .find(&grid)
+            .into_iter()
+            .map(|m| (m.word, m.start, m.direction))
+            .collect();
+        // Both spellings of Ü sit in the third column
+        assert_eq!(
+            found,
+            [
+                (0, Point::new(0, 0), Direction8::Right),
+                (1, Point::new(2, 0), Direction8::Down)
+            ]
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use aoc_core::ParseError;
+use aoc_grid::{Grid, Point};
+
+/// A small grid of letters to look for, `None` cells match anything.
+pub type Pattern = Grid<Option<String>>;
+
+/// One letter (grapheme cluster) per cell, `.` is a wildcard.
+pub fn parse_template(text: &str) -> Result<Pattern, ParseError> {
+    Grid::parse_graphemes(text, "a letter or `.`", |letter| {
+        Some((letter != ".").then(|| letter.to_owned()))
+    })
+}
+
+/// Where a template was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Placement {
+    pub top_left: Point<usize>,
+    /// Index into [`TemplateMatcher::variants`].
+    pub variant: usize,
+}
+
+/// Looks for a template, optionally in every orientation. Orientations that
+/// look the same (X-MAS mirrored is X-MAS rotated) are only tried once, so
+/// nothing is counted twice.
+pub struct TemplateMatcher {
+    variants: Vec<Pattern>,
+}
+
+impl TemplateMatcher {
+    pub fn new(template: Pattern, rotations: bool, reflections: bool) -> TemplateMatcher {
+        let mut variants = vec![template];
+        if reflections {
+            variants.push(variants[0].flipped_horizontally());
+        }
+        if rotations {
+            for i in 0..variants.len() {
+                let mut rotated = variants[i].clone();
+                for _ in 0..3 {
+                    rotated = rotated.rotated_right();
+                    variants.push(rotated.clone());
+                }
+            }
+        }
+
+        let mut distinct: Vec<Pattern> = Vec::new();
+        for variant in variants {
+            if !distinct.contains(&variant) {
+                distinct.push(variant);
+            }
+        }
+        TemplateMatcher { variants: distinct }
+    }
+
+    pub fn variants(&self) -> &[Pattern] {
+        &self.variants
+    }
+
+    fn matches_at(pattern: &Pattern, grid: &Grid<String>, top_left: Point<usize>) -> bool {
+        pattern.iter().all(|(offset, cell)| match cell {
+            Some(letter) => grid[top_left + offset] == *letter,
+            None => true,
+        })
+    }
+
+    /// Every placement of every variant, ordered by position.
+    pub fn find(&self, grid: &Grid<String>) -> Vec<Placement> {
+        let mut placements = Vec::new();
+        for (index, pattern) in self.variants.iter().enumerate() {
+            let (Some(columns), Some(rows)) = (
+                (grid.width() + 1).checked_sub(pattern.width()),
+                (grid.height() + 1).checked_sub(pattern.height()),
+            ) else {
+                continue;
+            };
+            for y in 0..rows {
+                for x in 0..columns {
+                    let top_left = Point::new(x, y);
+                    if Self::matches_at(pattern, grid, top_left) {
+                        placements.push(Placement {
+                            top_left,
+                            variant: index,
+                        });
+                    }
+                }
+            }
+        }
+        placements.sort_unstable();
+        placements
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const X_MAS: &str = include_str!("../templates/x-mas.txt");
+
+    #[test]
+    fn symmetric_variants_are_tried_once() {
+        let template = parse_template(X_MAS).unwrap();
+        assert_eq!(
+            TemplateMatcher::new(template.clone(), false, false)
+                .variants()
+                .len(),
+            1
+        );
+        assert_eq!(
+            TemplateMatcher::new(template.clone(), true, false)
+                .variants()
+                .len(),
+            4
+        );
+        assert_eq!(
+            TemplateMatcher::new(template, true, true).variants().len(),
+            4
+        );
+
+        // A straight line turned twice is its own reflection
+        let line = parse_template("AB.").unwrap();
+        assert_eq!(TemplateMatcher::new(line, true, true).variants().len(), 4);
+        let corner = parse_template("AB\nC.").unwrap();
+        assert_eq!(TemplateMatcher::new(corner, true, true).variants().len(), 8);
+    }
+
+    #[test]
+    fn wildcards_match_anything() {
+        let grid = crate::parse_grid("ABC\nAXC\n").unwrap();
+        let matcher = TemplateMatcher::new(parse_template("A.C").unwrap(), false, false);
+        let found: Vec<Point<usize>> = matcher
+            .find(&grid)
+            .into_iter()
+            .map(|placement| placement.top_left)
+            .collect();
+        assert_eq!(found, [Point::new(0, 0), Point::new(0, 1)]);
+    }
+}
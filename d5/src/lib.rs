@@ -0,0 +1,156 @@
+pub mod ordering;
+pub mod rules;
+
+use aoc_core::{
+    parse::{self, Cursor},
+    ParseError, Solution, SolveError,
+};
+use rules::RuleSet;
+
+/// The pages of an update and the line they were listed on.
+#[derive(Debug)]
+pub struct Update {
+    pub line: usize,
+    pub pages: Vec<u32>,
+}
+
+/// The page ordering rules (`before|after`) followed by the updates.
+#[derive(Debug)]
+pub struct SafetyManual {
+    pub rules: RuleSet,
+    pub updates: Vec<Update>,
+}
+
+fn get_middle_number(update: &[u32]) -> u32 {
+    // Parsing makes sure all updates contain an odd number of pages
+    update[update.len() / 2]
+}
+
+/// Updates need an odd number of pages, otherwise they have no middle page.
+pub fn parse_manual(contents: &str) -> Result<SafetyManual, ParseError> {
+    let mut manual = SafetyManual {
+        rules: RuleSet::new(),
+        updates: Vec::new(),
+    };
+
+    let mut updates_section = false;
+    for (number, line) in parse::lines(contents) {
+        if line.is_empty() && !updates_section {
+            updates_section = true;
+            continue;
+        }
+
+        let mut cursor = Cursor::new(number, line);
+        if updates_section {
+            let mut update = vec![cursor.number()?];
+            while !cursor.is_at_end() {
+                cursor.expect(",")?;
+                update.push(cursor.number()?);
+            }
+            if update.len() % 2 == 0 {
+                return Err(cursor.error("`,` followed by another page"));
+            }
+            manual.updates.push(Update {
+                line: number,
+                pages: update,
+            });
+        } else {
+            let before = cursor.number()?;
+            cursor.expect("|")?;
+            let after = cursor.number()?;
+            cursor.end()?;
+            manual.rules.insert(before, after);
+        }
+    }
+
+    Ok(manual)
+}
+
+/// Sum of the middle pages of the updates already in the right order.
+pub fn get_correct_middle_nums(manual: &SafetyManual) -> u32 {
+    manual
+        .updates
+        .iter()
+        .filter(|update| manual.rules.is_valid(&update.pages))
+        .map(|update| get_middle_number(&update.pages))
+        .sum()
+}
+
+/// Sum of the middle pages of the updates in the wrong order, once they are
+/// put in the right one. Fails on the first update the rules don't give a
+/// single order.
+pub fn get_incorrect_middle_nums(manual: &SafetyManual) -> Result<u32, SolveError> {
+    let mut sum = 0;
+    for update in &manual.updates {
+        if manual.rules.is_valid(&update.pages) {
+            continue;
+        }
+        let repaired = manual.rules.repair(&update.pages).map_err(|error| {
+            SolveError(format!(
+                "the update on line {} can't be repaired, {error}",
+                update.line
+            ))
+        })?;
+        sum += get_middle_number(&repaired);
+    }
+    Ok(sum)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = SafetyManual;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_manual(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        get_correct_middle_nums(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        get_incorrect_middle_nums(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part1(&input), 143);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part2(&input), Ok(123));
+    }
+
+    #[test]
+    fn updates_need_a_unique_order() {
+        let manual = parse_manual("1|2\n2|3\n3|1\n\n1,2,3\n").unwrap();
+        assert_eq!(
+            Day5::part2(&manual).unwrap_err().to_string(),
+            "the update on line 5 can't be repaired, the rules 1|2, 2|3, 3|1 form a cycle"
+        );
+
+        // Correct updates are fine without a rule between every two pages
+        let manual = parse_manual("1|2\n\n1,3,2\n2,3,1\n").unwrap();
+        assert_eq!(Day5::part1(&manual), 3);
+        assert_eq!(
+            Day5::part2(&manual).unwrap_err().to_string(),
+            "the update on line 4 can't be repaired, \
+             no rule decides whether 3 or 1 comes first"
+        );
+    }
+}
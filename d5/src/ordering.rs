@@ -0,0 +1,138 @@
+use std::{
+    collections::VecDeque,
+    fmt::{self, Display},
+};
+
+use crate::rules::RuleSet;
+
+/// Why the rules don't give the pages of an update one order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderError {
+    /// The rules between these pages go round in a circle, each page has to
+    /// come before the next one and the last before the first.
+    Cycle(Vec<u32>),
+    /// Nothing decides which of the two pages comes first.
+    Ambiguous(u32, u32),
+}
+
+impl Display for OrderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OrderError::Cycle(pages) => {
+                let rules: Vec<String> = pages
+                    .iter()
+                    .zip(pages.iter().cycle().skip(1))
+                    .map(|(before, after)| format!("{before}|{after}"))
+                    .collect();
+                write!(f, "the rules {} form a cycle", rules.join(", "))
+            }
+            OrderError::Ambiguous(first, second) => {
+                write!(f, "no rule decides whether {first} or {second} comes first")
+            }
+        }
+    }
+}
+
+impl std::error::Error for OrderError {}
+
+/// Puts `pages` in the one order `rules` allow, using Kahn's algorithm on the
+/// rules between these pages. Rules involving other pages don't matter, so
+/// only pairs of these pages are looked up. Pages are expected to appear
+/// only once.
+pub fn order(pages: &[u32], rules: &RuleSet) -> Result<Vec<u32>, OrderError> {
+    let mut successors = vec![Vec::new(); pages.len()];
+    let mut predecessors = vec![0; pages.len()];
+    for (before, &first) in pages.iter().enumerate() {
+        for (after, &second) in pages.iter().enumerate() {
+            if rules.requires(first, second) {
+                successors[before].push(after);
+                predecessors[after] += 1;
+            }
+        }
+    }
+
+    let mut ready: VecDeque<usize> = (0..pages.len())
+        .filter(|&page| predecessors[page] == 0)
+        .collect();
+    let mut ambiguous = None;
+    let mut ordered = Vec::with_capacity(pages.len());
+    while let Some(page) = ready.pop_front() {
+        // Any other page that is ready could just as well come next
+        if let (None, Some(&other)) = (&ambiguous, ready.front()) {
+            ambiguous = Some(OrderError::Ambiguous(pages[page], pages[other]));
+        }
+        ordered.push(pages[page]);
+        for &after in &successors[page] {
+            predecessors[after] -= 1;
+            if predecessors[after] == 0 {
+                ready.push_back(after);
+            }
+        }
+    }
+
+    // A cycle is the bigger problem, so it is reported even when the order
+    // was ambiguous before running into it
+    if ordered.len() < pages.len() {
+        return Err(OrderError::Cycle(find_cycle(pages, rules, &predecessors)));
+    }
+    match ambiguous {
+        Some(err) => Err(err),
+        None => Ok(ordered),
+    }
+}
+
+/// Every page Kahn's algorithm couldn't place still has a predecessor that
+/// wasn't placed either, so walking from one to the next backwards has to
+/// come round to a page seen before.
+fn find_cycle(pages: &[u32], rules: &RuleSet, predecessors: &[usize]) -> Vec<u32> {
+    let stuck: Vec<u32> = pages
+        .iter()
+        .zip(predecessors)
+        .filter(|(_, &count)| count > 0)
+        .map(|(&page, _)| page)
+        .collect();
+
+    let mut path = vec![stuck[0]];
+    loop {
+        let page = *path.last().unwrap();
+        let before = *stuck
+            .iter()
+            .find(|&&before| rules.requires(before, page))
+            .expect("pages left over by Kahn's algorithm have a predecessor");
+        if let Some(start) = path.iter().position(|&seen| seen == before) {
+            let mut cycle = path.split_off(start);
+            cycle.reverse();
+            // Start with the smallest page so the same cycle always reads
+            // the same
+            let smallest = (0..cycle.len()).min_by_key(|&i| cycle[i]).unwrap();
+            cycle.rotate_left(smallest);
+            return cycle;
+        }
+        path.push(before);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orders_by_the_rules_between_the_pages() {
+        let rules: RuleSet = [(97, 75), (75, 47), (47, 29), (97, 13), (61, 13)]
+            .into_iter()
+            .collect();
+        assert_eq!(order(&[75, 97, 47], &rules), Ok(vec![97, 75, 47]));
+        assert_eq!(
+            order(&[13, 61, 97], &rules),
+            Err(OrderError::Ambiguous(61, 97))
+        );
+    }
+
+    #[test]
+    fn reports_cycles() {
+        let rules: RuleSet = [(1, 2), (2, 3), (3, 1), (4, 1)].into_iter().collect();
+        let err = order(&[4, 3, 2, 1], &rules).unwrap_err();
+        assert_eq!(err, OrderError::Cycle(vec![1, 2, 3]));
+        assert_eq!(err.to_string(), "the rules 1|2, 2|3, 3|1 form a cycle");
+    }
+}
@@ -0,0 +1,323 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::ordering::{order, OrderError};
+
+/// Two pages of an update in the wrong order: the page at `early` comes
+/// first although a rule says the page at `late` has to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub early: usize,
+    pub late: usize,
+}
+
+/// The page ordering rules, kept in a set so checking whether a rule exists
+/// takes the same time however many rules there are.
+#[derive(Debug, Clone, Default)]
+pub struct RuleSet {
+    rules: HashSet<(u32, u32)>,
+}
+
+impl RuleSet {
+    pub fn new() -> RuleSet {
+        RuleSet::default()
+    }
+
+    /// Adds the rule `before|after`.
+    pub fn insert(&mut self, before: u32, after: u32) {
+        self.rules.insert((before, after));
+    }
+
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Whether a rule says `before` has to come before `after`.
+    pub fn requires(&self, before: u32, after: u32) -> bool {
+        self.rules.contains(&(before, after))
+    }
+
+    pub fn is_valid(&self, update: &[u32]) -> bool {
+        self.first_violation(update).is_none()
+    }
+
+    /// The first two pages (by the position of the earlier one, then of the
+    /// later one) that break a rule.
+    pub fn first_violation(&self, update: &[u32]) -> Option<Violation> {
+        (0..update.len()).find_map(|early| {
+            (early + 1..update.len())
+                .find(|&late| self.requires(update[late], update[early]))
+                .map(|late| Violation { early, late })
+        })
+    }
+
+    /// The pages of `update` in the one order the rules allow.
+    pub fn repair(&self, update: &[u32]) -> Result<Vec<u32>, OrderError> {
+        order(update, self)
+    }
+
+    /// In how many orders the pages of `update` break no rule, saturating
+    /// at `u64::MAX`.
+    ///
+    /// Pages no rule connects (directly or through other pages of the
+    /// update) interleave freely, so the update is split into such groups
+    /// and their counts are combined with binomial coefficients. Within a
+    /// group the orders are counted by the set of pages placed so far,
+    /// remembering the count of every set that can come first, so time and
+    /// memory grow with the number of those sets. A chain of rules has only
+    /// one such set per length, but a group whose rules leave many choices
+    /// can have exponentially many. Counting gives up and
+    /// returns `None` once a group needs more than [`MAX_PLACED_SETS`] of
+    /// them.
+    pub fn count_valid_orderings(&self, update: &[u32]) -> Option<u64> {
+        let mut count: u64 = 1;
+        let mut placed = 0;
+        for group in self.groups(update) {
+            // Page `j` of the group is in `predecessors[i]` if it has to
+            // come before page `i`
+            let predecessors: Vec<Pages> = group
+                .iter()
+                .map(|&page| {
+                    let mut before = Pages::new(group.len());
+                    for (j, &other) in group.iter().enumerate() {
+                        if self.requires(other, page) {
+                            before.insert(j);
+                        }
+                    }
+                    before
+                })
+                .collect();
+            let orders = count_orders(&predecessors)?;
+
+            placed += group.len();
+            count = count
+                .saturating_mul(binomial(placed, group.len()))
+                .saturating_mul(orders);
+        }
+        Some(count)
+    }
+
+    /// Splits `update` into groups of pages connected by rules, keeping the
+    /// order of the update within each group.
+    fn groups(&self, update: &[u32]) -> Vec<Vec<u32>> {
+        let mut group_of: Vec<Option<usize>> = vec![None; update.len()];
+        let mut groups = Vec::new();
+        for first in 0..update.len() {
+            if group_of[first].is_some() {
+                continue;
+            }
+            let group = groups.len();
+            group_of[first] = Some(group);
+            let mut members = vec![first];
+            let mut next = 0;
+            while next < members.len() {
+                let page = update[members[next]];
+                next += 1;
+                for other in 0..update.len() {
+                    let related =
+                        self.requires(page, update[other]) || self.requires(update[other], page);
+                    if group_of[other].is_none() && related {
+                        group_of[other] = Some(group);
+                        members.push(other);
+                    }
+                }
+            }
+            members.sort_unstable();
+            groups.push(members.into_iter().map(|index| update[index]).collect());
+        }
+        groups
+    }
+}
+
+/// How many sets of placed pages [`RuleSet::count_valid_orderings`]
+/// remembers per group of pages before it gives up.
+pub const MAX_PLACED_SETS: usize = 1 << 16;
+
+/// A set of pages of a group, by their index in the group.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Pages(Vec<u64>);
+
+impl Pages {
+    fn new(len: usize) -> Pages {
+        Pages(vec![0; len.div_ceil(64)])
+    }
+
+    fn contains(&self, page: usize) -> bool {
+        self.0[page / 64] & 1 << (page % 64) != 0
+    }
+
+    fn insert(&mut self, page: usize) {
+        self.0[page / 64] |= 1 << (page % 64);
+    }
+
+    fn remove(&mut self, page: usize) {
+        self.0[page / 64] &= !(1 << (page % 64));
+    }
+
+    fn is_subset(&self, other: &Pages) -> bool {
+        self.0
+            .iter()
+            .zip(&other.0)
+            .all(|(words, other)| words & !other == 0)
+    }
+}
+
+/// A set of placed pages [`count_orders`] is counting the orders after.
+struct Frame {
+    /// The page placed last to get to the set, `None` for the empty set.
+    page: Option<usize>,
+    /// The next page to try placing.
+    next: usize,
+    /// Orders counted so far.
+    count: u64,
+}
+
+/// Orders of the pages of a group, given which pages have to come before
+/// each of them. `None` if that takes more than [`MAX_PLACED_SETS`] sets.
+///
+/// Walks the sets of placed pages depth first with a stack of its own
+/// rather than by recursion, so groups of any length fit.
+fn count_orders(predecessors: &[Pages]) -> Option<u64> {
+    let len = predecessors.len();
+    if len == 0 {
+        return Some(1);
+    }
+    let mut memo: HashMap<Pages, u64> = HashMap::new();
+    let mut placed = Pages::new(len);
+    let mut placed_len = 0;
+    let mut stack = vec![Frame {
+        page: None,
+        next: 0,
+        count: 0,
+    }];
+
+    while let Some(frame) = stack.last_mut() {
+        let page = (frame.next..len)
+            .find(|&page| !placed.contains(page) && predecessors[page].is_subset(&placed));
+        if let Some(page) = page {
+            frame.next = page + 1;
+            placed.insert(page);
+            placed_len += 1;
+            let known = if placed_len == len {
+                Some(1)
+            } else {
+                memo.get(&placed).copied()
+            };
+            match known {
+                Some(orders) => {
+                    frame.count = frame.count.saturating_add(orders);
+                    placed.remove(page);
+                    placed_len -= 1;
+                }
+                None if memo.len() >= MAX_PLACED_SETS => return None,
+                None => stack.push(Frame {
+                    page: Some(page),
+                    next: 0,
+                    count: 0,
+                }),
+            }
+            continue;
+        }
+
+        // Every page that can come next has been tried
+        let Frame { page, count, .. } = stack.pop()?;
+        let Some(page) = page else {
+            return Some(count);
+        };
+        memo.insert(placed.clone(), count);
+        placed.remove(page);
+        placed_len -= 1;
+        let parent = stack.last_mut()?;
+        parent.count = parent.count.saturating_add(count);
+    }
+    None
+}
+
+/// `n` choose `k`, saturating at `u64::MAX`.
+fn binomial(n: usize, k: usize) -> u64 {
+    let k = k.min(n - k);
+    // Pascal's triangle, only the first `k + 1` entries of each row
+    let mut row = vec![0u64; k + 1];
+    row[0] = 1;
+    for i in 1..=n {
+        for j in (1..=k.min(i)).rev() {
+            row[j] = row[j].saturating_add(row[j - 1]);
+        }
+    }
+    row[k]
+}
+
+impl FromIterator<(u32, u32)> for RuleSet {
+    fn from_iter<I: IntoIterator<Item = (u32, u32)>>(rules: I) -> RuleSet {
+        RuleSet {
+            rules: rules.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules() -> RuleSet {
+        [
+            (47, 53),
+            (97, 13),
+            (97, 61),
+            (75, 29),
+            (61, 13),
+            (75, 53),
+            (29, 13),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn finds_the_first_violation() {
+        let rules = rules();
+        assert!(rules.is_valid(&[75, 47, 61, 53, 29]));
+        assert_eq!(
+            rules.first_violation(&[61, 13, 29, 75]),
+            Some(Violation { early: 1, late: 2 })
+        );
+        assert_eq!(rules.repair(&[29, 75, 13]), Ok(vec![75, 29, 13]));
+    }
+
+    #[test]
+    fn counts_orders_allowed_by_the_rules() {
+        let rules = rules();
+        assert_eq!(rules.count_valid_orderings(&[75, 29, 13]), Some(1));
+        // 97 before 61 before 13, 47 anywhere
+        assert_eq!(rules.count_valid_orderings(&[13, 47, 61, 97]), Some(4));
+        assert_eq!(rules.count_valid_orderings(&[1, 2, 3, 4, 5]), Some(120));
+        let cycle: RuleSet = [(1, 2), (2, 3), (3, 1)].into_iter().collect();
+        assert_eq!(cycle.count_valid_orderings(&[1, 2, 3, 4]), Some(0));
+        // 20! fits, 21! doesn't
+        let unrelated: Vec<u32> = (1..=21).collect();
+        assert_eq!(
+            rules.count_valid_orderings(&unrelated[..20]),
+            Some(2_432_902_008_176_640_000)
+        );
+        assert_eq!(rules.count_valid_orderings(&unrelated), Some(u64::MAX));
+    }
+
+    #[test]
+    fn long_updates_are_counted_or_refused() {
+        // A chain has a single order, placing its pages one after another
+        // goes as deep as the chain is long
+        let chain: RuleSet = (1..1000).map(|page| (page, page + 1)).collect();
+        let pages: Vec<u32> = (1..=1000).rev().collect();
+        assert_eq!(chain.count_valid_orderings(&pages), Some(1));
+
+        // 40 pages that only have to come after page 0 can be placed in too
+        // many different sets to count
+        let star: RuleSet = (1..=40).map(|page| (0, page)).collect();
+        let pages: Vec<u32> = (0..=40).collect();
+        assert_eq!(star.count_valid_orderings(&pages), None);
+        assert_eq!(star.count_valid_orderings(&pages[..11]), Some(3_628_800));
+    }
+}
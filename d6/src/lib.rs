@@ -0,0 +1,146 @@
+use std::fmt;
+
+use aoc_core::{ParseError, Solution, SolveError};
+use aoc_grid::{Direction, Grid, Point};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Guard {
+    position: Point<usize>,
+    direction: Direction,
+}
+
+#[derive(Clone, Debug)]
+pub struct Tile {
+    visited: [bool; 4],
+    wall: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SimulationResult {
+    LoopDetected,
+    ExitedGrid(usize),
+}
+
+impl fmt::Display for SimulationResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SimulationResult::ExitedGrid(count) => write!(f, "{count}"),
+            SimulationResult::LoopDetected => write!(f, "Loop Detected"),
+        }
+    }
+}
+
+pub struct Lab {
+    pub guard: Guard,
+    pub grid: Grid<Tile>,
+}
+
+pub fn run_simulation(mut guard: Guard, mut grid: Grid<Tile>) -> SimulationResult {
+    let mut visited_count = 1;
+
+    // Step until the guard leaves the grid
+    while let Some(position) = grid.step(guard.position, guard.direction) {
+        if grid[position].wall {
+            guard.direction = guard.direction.turned_right();
+        } else {
+            guard.position = position;
+            // Detect Loop
+            if grid[position].visited[guard.direction as usize] {
+                return SimulationResult::LoopDetected;
+            }
+            if !grid[position].visited.contains(&true) {
+                visited_count += 1;
+                grid[position].visited[guard.direction as usize] = true;
+            }
+        }
+    }
+    SimulationResult::ExitedGrid(visited_count)
+}
+
+pub fn parse_lab(contents: &str) -> Result<Lab, ParseError> {
+    let chars = Grid::parse(contents, "`.`, `#` or the guard", |char| {
+        (matches!(char, '.' | '#') || Direction::from_char(char).is_some()).then_some(char)
+    })?;
+    let mut grid = chars.map(|&char| Tile {
+        visited: [false; 4],
+        wall: char == '#',
+    });
+    let mut guard = None;
+
+    // Search for the guards initial position
+    for (position, &char) in chars.iter() {
+        let Some(direction) = Direction::from_char(char) else {
+            continue;
+        };
+        grid[position].visited[direction as usize] = true;
+        guard = Some(Guard {
+            position,
+            direction,
+        });
+    }
+
+    let guard = guard.ok_or_else(|| {
+        ParseError::end_of_input(chars.height(), "the guard (`^`, `>`, `v` or `<`)")
+    })?;
+    Ok(Lab { guard, grid })
+}
+
+pub fn count_loop_positions(lab: &Lab) -> usize {
+    let Lab { guard, grid } = lab;
+
+    // Loop over the entire grid and if there's not a wall or the starting
+    // position of the guard, place a wall and simulate and check if there's a loop
+
+    let mut stuck_count = 0;
+    for (position, tile) in grid.iter() {
+        if !tile.wall && position != guard.position {
+            let mut new_grid = grid.clone();
+            new_grid[position].wall = true;
+            if run_simulation(*guard, new_grid) == SimulationResult::LoopDetected {
+                stuck_count += 1;
+            }
+        }
+    }
+    stuck_count
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Lab;
+    type Part1 = SimulationResult;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lab(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        run_simulation(input.guard, input.grid.clone())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(count_loop_positions(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part1(&input), SimulationResult::ExitedGrid(41));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part2(&input), Ok(6));
+    }
+}
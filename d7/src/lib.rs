@@ -0,0 +1,100 @@
+use aoc_core::{
+    parse::{self, Cursor},
+    ParseError, Solution, SolveError,
+};
+use itertools::Itertools;
+
+pub type Equation = (u64, Vec<u64>);
+
+pub fn concat(a: u64, b: u64) -> u64 {
+    a * 10u64.pow(b.ilog10() + 1) + b
+}
+
+pub fn parse_equations(contents: &str) -> Result<Vec<Equation>, ParseError> {
+    let mut equations: Vec<Equation> = Vec::new();
+    for (number, line) in parse::lines(contents) {
+        let mut cursor = Cursor::new(number, line);
+        let result: u64 = cursor.number()?;
+        cursor.expect(":")?;
+        let mut parts: Vec<u64> = Vec::new();
+        while parts.is_empty() || !cursor.is_at_end() {
+            cursor.expect(" ")?;
+            parts.push(cursor.number()?);
+        }
+        equations.push((result, parts));
+    }
+    Ok(equations)
+}
+
+pub fn get_sums(equations: &[Equation], operations: Vec<fn(u64, u64) -> u64>) -> u64 {
+    let mut sum: u64 = 0;
+    for equation in equations {
+        // Loop over all possible combinations of operations
+
+        'sequence: for operation_sequence in
+            std::iter::repeat_n(&operations, equation.1.len() - 1).multi_cartesian_product()
+        {
+            let mut tmp: u64 = equation.1[0];
+            for (i, operation) in operation_sequence.iter().enumerate() {
+                if tmp > equation.0 {
+                    continue 'sequence;
+                }
+                tmp = operation(tmp, equation.1[i + 1]);
+            }
+            if tmp == equation.0 {
+                sum += tmp;
+                break;
+            }
+        }
+        // If the equation was only one number, check for equality
+        if equation.1.len() == 1 && equation.0 == equation.1[0] {
+            sum += equation.0;
+        }
+    }
+
+    sum
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Equation>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_equations(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        get_sums(input, vec![|a: u64, b: u64| a * b, |a: u64, b: u64| a + b])
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(get_sums(
+            input,
+            vec![|a: u64, b: u64| a * b, |a: u64, b: u64| a + b, concat],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part1(&input), 3749);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part2(&input), Ok(11387));
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use aoc_core::{ParseError, Solution, SolveError};
+use aoc_grid::{Grid, Point};
+
+pub struct AntennaMap {
+    pub antennas: HashMap<char, Vec<Point<usize>>>,
+    pub width: usize,
+    pub height: usize,
+}
+
+pub fn antinode_count_distance(map: &AntennaMap) -> usize {
+    let mut antinodes = Grid::new(map.width, map.height, false);
+    let mut antinode_count = 0;
+    for positions in map.antennas.values() {
+        for &pos1 in positions {
+            for &pos2 in positions {
+                if pos1 == pos2 {
+                    continue;
+                }
+                let delta = pos2.signed() - pos1.signed();
+
+                if let Some(position) = antinodes.offset(pos1, -delta) {
+                    if !antinodes[position] {
+                        antinode_count += 1;
+                        antinodes[position] = true;
+                    }
+                }
+            }
+        }
+    }
+
+    antinode_count
+}
+
+pub fn antinode_count(map: &AntennaMap) -> usize {
+    let mut antinodes = Grid::new(map.width, map.height, false);
+    let mut antinode_count = 0;
+    for positions in map.antennas.values() {
+        for &pos1 in positions {
+            for &pos2 in positions {
+                if pos1 == pos2 {
+                    continue;
+                }
+
+                let delta = pos2.signed() - pos1.signed();
+                let mut current = Some(pos1);
+
+                while let Some(position) = current {
+                    if !antinodes[position] {
+                        antinode_count += 1;
+                        antinodes[position] = true;
+                    }
+                    current = antinodes.offset(position, -delta);
+                }
+            }
+        }
+    }
+
+    antinode_count
+}
+
+pub fn parse_map(contents: &str) -> Result<AntennaMap, ParseError> {
+    let grid = Grid::parse(contents, "`.` or an antenna (letter or digit)", |char| {
+        (char == '.' || char.is_ascii_alphanumeric()).then_some(char)
+    })?;
+
+    let mut antennas: HashMap<char, Vec<Point<usize>>> = HashMap::new();
+    for (position, &char) in grid.iter() {
+        match char {
+            '.' => continue,
+            character => antennas.entry(character).or_default().push(position),
+        }
+    }
+
+    Ok(AntennaMap {
+        antennas,
+        width: grid.width(),
+        height: grid.height(),
+    })
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = AntennaMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        antinode_count_distance(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(antinode_count(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(Day8::part1(&input), 14);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(Day8::part2(&input), Ok(34));
+    }
+}
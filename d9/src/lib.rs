@@ -0,0 +1,213 @@
+use aoc_core::{
+    parse::{self, Cursor, Found},
+    ParseError, Solution, SolveError,
+};
+
+#[derive(Debug)]
+struct File {
+    id: Option<usize>,
+    blocks: u32,
+}
+
+pub fn get_fragmented_checksum(diskmap: &[char]) -> i128 {
+    let mut disk: Vec<i32> = Vec::new();
+
+    for i in (0..diskmap.len()).step_by(2) {
+        let blocksize: u32 = diskmap[i].to_digit(10).unwrap();
+        let free_space: u32 = if i == diskmap.len() - 1 {
+            '0'
+        } else {
+            diskmap[i + 1]
+        }
+        .to_digit(10)
+        .unwrap();
+        for _ in 0..blocksize {
+            disk.push(i as i32 / 2);
+        }
+        for _ in 0..free_space {
+            disk.push(-1);
+        }
+    }
+
+    let mut next_free_space = 0;
+    'outer: for i in (0..disk.len()).rev() {
+        if disk[i] == -1 {
+            continue;
+        }
+        for (j, &block) in disk.iter().enumerate().skip(next_free_space) {
+            if j > i {
+                break 'outer;
+            }
+            if block == -1 {
+                next_free_space = j;
+                break;
+            }
+        }
+        disk[next_free_space] = disk[i];
+        disk[i] = -1;
+    }
+
+    let mut sum: i128 = 0;
+    for (i, id) in disk.iter().enumerate() {
+        if *id == -1 {
+            break;
+        }
+        sum += (i as i128) * (*id as i128);
+    }
+
+    sum
+}
+
+pub fn get_unfragmented_checksum(diskmap: &[char]) -> i128 {
+    let mut disk: Vec<File> = Vec::new();
+
+    // Initial disk population
+    for i in (0..diskmap.len()).step_by(2) {
+        let blocksize: u32 = diskmap[i].to_digit(10).unwrap();
+        let free_space: u32 = if i == diskmap.len() - 1 {
+            '0'
+        } else {
+            diskmap[i + 1]
+        }
+        .to_digit(10)
+        .unwrap();
+
+        disk.push(File {
+            blocks: blocksize,
+            id: Some(i / 2),
+        });
+        disk.push(File {
+            blocks: free_space,
+            id: None,
+        });
+    }
+
+    'outer: for move_to_front in (0..disk.len()).rev() {
+        if disk[move_to_front].id.is_none() {
+            continue;
+        }
+
+        let mut next_free_space_idx = None;
+        for (free_idx, potentially_free) in disk.iter().enumerate() {
+            if potentially_free.id.is_some() {
+                continue;
+            }
+            if move_to_front < free_idx {
+                continue 'outer;
+            }
+            if let Some(file_id) = disk[move_to_front].id {
+                if let Some(free_id) = disk[free_idx].id {
+                    if free_id > file_id {
+                        continue 'outer;
+                    }
+                }
+                if disk[free_idx].blocks >= disk[move_to_front].blocks {
+                    next_free_space_idx = Some(free_idx);
+                    break;
+                }
+            }
+        }
+
+        if let Some(free_idx) = next_free_space_idx {
+            let current_blocks = disk[move_to_front].blocks;
+            let current_id = disk[move_to_front].id;
+
+            if disk[free_idx].blocks == current_blocks {
+                // If it fits exactly
+                disk[free_idx].id = current_id;
+                disk[move_to_front].id = None;
+            } else {
+                // If we need to split
+                disk[free_idx].blocks -= current_blocks;
+                disk[move_to_front].id = None;
+
+                // Insert new used file before the free space
+                disk.insert(
+                    free_idx,
+                    File {
+                        id: current_id,
+                        blocks: current_blocks,
+                    },
+                );
+            }
+        }
+    }
+
+    let mut idx = 0;
+    let mut sum = 0;
+    for file in &disk {
+        for _ in 0..file.blocks {
+            if let Some(file_id) = file.id {
+                sum += (idx as i128) * (file_id as i128);
+            }
+            idx += 1;
+        }
+    }
+
+    sum
+}
+
+/// The disk map is a single line of digits, checked here so the checksums can
+/// rely on `to_digit` succeeding.
+pub fn parse_diskmap(contents: &str) -> Result<Vec<char>, ParseError> {
+    let mut lines = parse::lines(contents.trim_end());
+    let Some((number, line)) = lines.next() else {
+        return Err(ParseError::new(1, 1, "a digit", Found::EndOfInput));
+    };
+
+    let mut cursor = Cursor::new(number, line);
+    let mut diskmap = Vec::new();
+    while let Some(char) = cursor.peek() {
+        if !char.is_ascii_digit() {
+            return Err(cursor.error("a digit"));
+        }
+        diskmap.push(char);
+        cursor.next_char();
+    }
+
+    if let Some((number, line)) = lines.next() {
+        return Err(Cursor::new(number, line).error("end of input"));
+    }
+    Ok(diskmap)
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<char>;
+    type Part1 = i128;
+    type Part2 = i128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_diskmap(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        get_fragmented_checksum(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(get_unfragmented_checksum(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/example.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part1(&input), 1928);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part2(&input), Ok(2858));
+    }
+}